@@ -0,0 +1,174 @@
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
+use onchain_id::{claims_issuer::{self, traits::IClaimIssuer}, erc734::{MANAGEMENT_KEY, SECP256R1_KEY_TYPE}, erc735::traits::ERC735Client, error::OnChainIdError, key_store::{self, require_key_purpose}, signature};
+
+/**
+ * Identity of a claim issuer, mirroring the ClaimIssuer contract of the Solidity implementation.
+ * Its ERC-734 keys of purpose 3 (CLAIM) sign the claims it issues, its MANAGEMENT keys manage
+ * the keys and revoke the claims. Identities adding one of its claims and verifiers check the
+ * claim with {IClaimIssuer-isClaimValid}.
+ */
+#[contract]
+pub struct ClaimIssuer;
+
+#[contractimpl]
+impl ClaimIssuer {
+    /**
+    * Initializes the claim issuer by registering the first MANAGEMENT key.
+    * Can only be called once, by the address which deployed the claim issuer, so that nobody can
+    * initialize it between its deployment and its initialization. The key of an account owner must
+    * be its own public key.
+    * @param _ownerKey ed25519 public key of the owner, registered with purpose 1 = MANAGEMENT
+    * @param _ownerAddress address of the owner, which deployed the claim issuer and must authorize the initialization
+    * @param _salt the salt the claim issuer was deployed with
+    */
+    pub fn initialize(e: Env, owner_key: BytesN<32>, owner_address: Address, salt: BytesN<32>) -> Result<(), OnChainIdError> {
+        if e.deployer().with_address(owner_address.clone(), salt).deployed_address() != e.current_contract_address() {
+            return Err(OnChainIdError::Unauthorized);
+        }
+        key_store::initialize(&e, owner_key, &owner_address)
+    }
+
+    /**
+    * See {IERC734-addKey}.
+    * Adds a purpose to a key of the claim issuer, e.g. 3 = CLAIM for the keys signing its claims.
+    * MUST only be done by keys of purpose 1, or the claim issuer itself.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the claim issuer itself
+    * @param _key public key
+    * @param _purpose a uint256 specifying the key type, like 1 = MANAGEMENT, 2 = ACTION, 3 = CLAIM, 4 = ENCRYPTION
+    * @param _type type of key used, e.g. 1 = ECDSA, 2 = RSA, 3 = ED25519
+    * @return success Returns TRUE if the addition was successful
+    */
+    pub fn add_key(e: Env, caller: Address, key: BytesN<32>, purpose: u32, key_type: u32) -> Result<bool, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        key_store::store_key(&e, key, purpose, key_type, None)?;
+        Ok(true)
+    }
+
+    /**
+    * Adds a secp256r1 (P-256) key to the claim issuer, registered under keccak256 of the public key.
+    * MUST only be done by keys of purpose 1, or the claim issuer itself.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the claim issuer itself
+    * @param _publicKey the uncompressed SEC-1 encoded public key
+    * @param _purpose a uint256 specifying the key type, like 1 = MANAGEMENT, 2 = ACTION, 3 = CLAIM, 4 = ENCRYPTION
+    * @return key Returns the key under which the public key is registered
+    */
+    pub fn add_secp256r1_key(e: Env, caller: Address, public_key: BytesN<65>, purpose: u32) -> Result<BytesN<32>, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        let key = signature::secp256r1_key_hash(&e, &public_key);
+        key_store::store_key(&e, key.clone(), purpose, SECP256R1_KEY_TYPE, Some(public_key))?;
+        Ok(key)
+    }
+
+    /**
+    * See {IERC734-removeKey}.
+    * Remove the purpose from a key.
+    * MUST only be done by keys of purpose 1, or the claim issuer itself.
+    */
+    pub fn remove_key(e: Env, caller: Address, key: BytesN<32>, purpose: u32) -> Result<bool, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        key_store::remove_key(&e, key, purpose)?;
+        Ok(true)
+    }
+
+    /**
+    * See {IERC734-getKey}.
+    * @param _key The public key
+    * @return purposes, keyType, key Returns the full key data, if present in the claim issuer.
+    */
+    pub fn get_key(e: Env, key: BytesN<32>) -> Result<(Vec<u32>, u32, BytesN<32>), OnChainIdError> {
+        let retrieved_key = key_store::get_key(&e, &key)?;
+        Ok((retrieved_key.purposes, retrieved_key.key_type, retrieved_key.key))
+    }
+
+    /**
+    * See {IERC734-getKeyPurposes}.
+    * @param _key The public key
+    * @return _purposes Returns the purposes of the specified key
+    */
+    pub fn get_key_purposes(e: Env, key: BytesN<32>) -> Result<Vec<u32>, OnChainIdError> {
+        Ok(key_store::get_key(&e, &key)?.purposes)
+    }
+
+    /**
+    * See {IERC734-getKeysByPurpose}.
+    * @param _purpose the purpose, like 1 = MANAGEMENT, 2 = ACTION, 3 = CLAIM, 4 = ENCRYPTION
+    * @return keys Returns an array of public key held by the claim issuer and having the specified purpose
+    */
+    pub fn get_keys_by_purpose(e: Env, purpose: u32) -> Vec<BytesN<32>> {
+        key_store::get_keys_by_purpose(&e, purpose)
+    }
+
+    /**
+    * See {IERC734-keyHasPurpose}.
+    * A MANAGEMENT key is considered to hold every purpose.
+    * @param _key The public key
+    * @param _purpose The purpose to check
+    * @return result Returns TRUE if the key has the purpose, FALSE otherwise
+    */
+    pub fn key_has_purpose(e: Env, key: BytesN<32>, purpose: u32) -> Result<bool, OnChainIdError> {
+        key_store::key_has_purpose(&e, &key, purpose)
+    }
+
+    /**
+    * Returns the message a CLAIM key signs to issue a claim on an identity: the 32 bytes contract id
+    * of the identity, the topic as 16 big-endian bytes, the validity window as two 8 bytes big-endian
+    * timestamps, then the claim data.
+    * @param _identity the identity contract holding the claim
+    * @param _topic the claim topic
+    * @param _data the claim data
+    * @param _validFrom ledger timestamp from which the claim is valid, 0 if valid right away
+    * @param _validUntil ledger timestamp until which the claim is valid, 0 if it does not expire
+    * @return message Returns the message to sign with the CLAIM key
+    */
+    pub fn claim_message(e: Env, identity: Address, topic: u128, data: Bytes, valid_from: u64, valid_until: u64) -> Bytes {
+        claims_issuer::claim_message(&e, &identity, topic, &data, valid_from, valid_until)
+    }
+}
+
+#[contractimpl]
+impl IClaimIssuer for ClaimIssuer {
+    /**
+     * See {IClaimIssuer-revokeClaim}.
+     * The signature of the claim is read with {IERC735-getClaim} of the identity.
+     */
+    fn revoke_claim(e: Env, caller: Address, claim_id: BytesN<32>, identity: Address) -> Result<bool, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        let signature = match ERC735Client::new(&e, &identity).try_get_claim(&claim_id) {
+            Ok(Ok((_, _, _, signature, _, _))) => signature,
+            _ => return Err(OnChainIdError::NoClaimFound),
+        };
+
+        claims_issuer::revoke_signature(&e, &signature)?;
+        Ok(true)
+    }
+
+    /**
+     * See {IClaimIssuer-revokeClaimBySignature}.
+     * Records keccak256 of the signature in the revoked claims of this issuer.
+     */
+    fn revoke_claim_by_signature(e: Env, caller: Address, signature: Bytes) -> Result<(), OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        claims_issuer::revoke_signature(&e, &signature)
+    }
+
+    /**
+     * See {IClaimIssuer-isClaimRevoked}.
+     */
+    fn is_claim_revoked(e: Env, sig: Bytes) -> bool {
+        claims_issuer::is_signature_revoked(&e, &sig)
+    }
+
+    /**
+     * See {IClaimIssuer-isClaimValid}.
+     * The signature is verified with the algorithm of the type of the signing key: ED25519,
+     * ECDSA (secp256k1) or SECP256R1.
+     */
+    fn is_claim_valid(e: Env, identity: Address, topic: u128, sig: Bytes, data: Bytes, valid_from: u64, valid_until: u64) -> Result<bool, OnChainIdError> {
+        claims_issuer::verify_claim(&e, &identity, topic, &sig, &data, valid_from, valid_until)
+    }
+}
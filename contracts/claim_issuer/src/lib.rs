@@ -0,0 +1,5 @@
+#![no_std]
+#![allow(clippy::too_many_arguments)]
+
+pub mod claim_issuer;
+mod test;
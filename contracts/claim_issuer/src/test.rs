@@ -0,0 +1,314 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::claim_issuer::{ClaimIssuer, ClaimIssuerClient};
+use onchain_id::{erc734::{ECDSA_KEY_TYPE, ED25519_KEY_TYPE, RSA_KEY_TYPE}, error::OnChainIdError, key_store::address_to_key, signature::ecdsa_key_hash};
+use identity::identity::{Identity, IdentityClient};
+use soroban_sdk::{testutils::{Address as _, BytesN as _, Events, Ledger}, vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec};
+use ed25519_dalek::{Keypair, Signer};
+use rand::thread_rng;
+use k256::ecdsa::SigningKey;
+use p256::ecdsa::{signature::Signer as _, Signature as P256Signature, SigningKey as P256SigningKey};
+
+// Registers a claim issuer initialized with the MANAGEMENT key of its owner address.
+fn create_claim_issuer(env: &Env) -> (ClaimIssuerClient<'_>, Address) {
+    env.mock_all_auths();
+    // The claim issuer is registered at the address of its deployment by its owner
+    let owner = Address::generate(env);
+    let salt = BytesN::random(env);
+    let contract_id = env.register_contract(Some(&env.deployer().with_address(owner.clone(), salt.clone()).deployed_address()), ClaimIssuer);
+    let client = ClaimIssuerClient::new(env, &contract_id);
+    client.initialize(&address_to_key(env, &owner), &owner, &salt);
+    (client, owner)
+}
+
+// Registers an identity initialized with the MANAGEMENT key of its owner address.
+fn create_identity(env: &Env) -> (IdentityClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, Identity);
+    let client = IdentityClient::new(env, &contract_id);
+
+    let owner = Address::generate(env);
+    client.initialize(&address_to_key(env, &owner), &owner);
+    (client, owner)
+}
+
+// Returns the last `count` events published in the test environment.
+fn last_events(env: &Env, count: u32) -> Vec<(Address, Vec<Val>, Val)> {
+    let events = env.events().all();
+    events.slice(events.len() - count..)
+}
+
+// Adds an ed25519 CLAIM key to the claim issuer and returns its key pair.
+fn add_claim_key(env: &Env, client: &ClaimIssuerClient<'_>, owner: &Address) -> Keypair {
+    let key_pair = Keypair::generate(&mut thread_rng());
+    client.add_key(owner, &BytesN::from_array(env, key_pair.public.as_bytes()), &3, &ED25519_KEY_TYPE);
+    key_pair
+}
+
+// Signs the claim message with an ed25519 key pair, the signature being prefixed with its public key.
+fn sign_claim(env: &Env, client: &ClaimIssuerClient<'_>, key_pair: &Keypair, identity: &Address, topic: u128, data: &Bytes, valid_from: u64, valid_until: u64) -> Bytes {
+    let message: std::vec::Vec<u8> = client.claim_message(identity, &topic, data, &valid_from, &valid_until).iter().collect();
+    let mut signature = Bytes::from_array(env, key_pair.public.as_bytes());
+    signature.extend_from_array(&key_pair.sign(&message).to_bytes());
+    signature
+}
+
+// Returns the ERC-734 key of a secp256k1 signing key.
+fn ecdsa_key(env: &Env, signing_key: &SigningKey) -> BytesN<32> {
+    let public_key = signing_key.verifying_key().to_encoded_point(false);
+    ecdsa_key_hash(env, &BytesN::from_array(env, public_key.as_bytes().try_into().unwrap()))
+}
+
+// Signs keccak256 of the claim message with a secp256k1 key, as a 65 bytes r || s || v signature
+// prefixed with `key`.
+fn sign_ecdsa_claim(env: &Env, client: &ClaimIssuerClient<'_>, key: &BytesN<32>, signing_key: &SigningKey, identity: &Address, topic: u128, data: &Bytes) -> Bytes {
+    let digest = env.crypto().keccak256(&client.claim_message(identity, &topic, data, &0, &0)).to_array();
+    let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&digest).unwrap();
+
+    let mut signature_bytes: Bytes = key.clone().into();
+    signature_bytes.extend_from_slice(&signature.to_bytes());
+    signature_bytes.push_back(recovery_id.to_byte() + 27);
+    signature_bytes
+}
+
+// Returns the SEC-1 encoded public key of a secp256r1 signing key.
+fn secp256r1_public_key(env: &Env, signing_key: &P256SigningKey) -> BytesN<65> {
+    let public_key = signing_key.verifying_key().to_encoded_point(false);
+    BytesN::from_array(env, public_key.as_bytes().try_into().unwrap())
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+
+    let owner_key = address_to_key(&env, &owner);
+    assert_eq!(client.get_keys_by_purpose(&1), vec![&env, owner_key.clone()]);
+    assert!(client.key_has_purpose(&owner_key, &3));
+
+    let attacker = Address::generate(&env);
+    assert_eq!(client.try_initialize(&address_to_key(&env, &attacker), &attacker, &BytesN::random(&env)), Err(Ok(OnChainIdError::Unauthorized)));
+}
+
+#[test]
+fn test_initialize_by_deployer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let owner = Address::generate(&env);
+    let salt = BytesN::random(&env);
+    let contract_id = env.register_contract(Some(&env.deployer().with_address(owner.clone(), salt.clone()).deployed_address()), ClaimIssuer);
+    let client = ClaimIssuerClient::new(&env, &contract_id);
+
+    // Nobody but the deployer initializes the claim issuer, even knowing its salt
+    let attacker = Address::generate(&env);
+    assert_eq!(client.try_initialize(&address_to_key(&env, &attacker), &attacker, &salt), Err(Ok(OnChainIdError::Unauthorized)));
+
+    client.initialize(&address_to_key(&env, &owner), &owner, &salt);
+    assert_eq!(client.get_keys_by_purpose(&1), vec![&env, address_to_key(&env, &owner)]);
+    assert_eq!(client.try_initialize(&address_to_key(&env, &owner), &owner, &salt), Err(Ok(OnChainIdError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_add_and_remove_key() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+
+    let key = BytesN::random(&env);
+    let stranger = Address::generate(&env);
+    assert_eq!(client.try_add_key(&stranger, &key, &3, &ED25519_KEY_TYPE), Err(Ok(OnChainIdError::Unauthorized)));
+
+    assert!(client.add_key(&owner, &key, &3, &ED25519_KEY_TYPE));
+    assert_eq!(client.get_key(&key), (vec![&env, 3], ED25519_KEY_TYPE, key.clone()));
+    assert_eq!(client.get_key_purposes(&key), vec![&env, 3]);
+
+    assert!(client.remove_key(&owner, &key, &3));
+    assert_eq!(client.try_get_key(&key), Err(Ok(OnChainIdError::KeyNotRegistered)));
+}
+
+#[test]
+fn test_is_claim_valid() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, identity_owner) = create_identity(&env);
+    let claim_key_pair = add_claim_key(&env, &client, &owner);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &client, &claim_key_pair, &identity_client.address, 1010101, &data, 0, 0);
+    assert!(client.is_claim_valid(&identity_client.address, &1010101, &signature, &data, &0, &0));
+
+    // The identity checks the claim with the issuer when it is added
+    let issuer = client.address.clone();
+    let claim_id = identity_client.add_claim(&identity_owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(identity_client.get_claim(&claim_id).3, signature);
+
+    // The claim cannot be replayed on another identity
+    let (other_identity_client, other_identity_owner) = create_identity(&env);
+    assert!(client.try_is_claim_valid(&other_identity_client.address, &1010101, &signature, &data, &0, &0).is_err());
+    let add_claim_result = other_identity_client.try_add_claim(&other_identity_owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::InvalidClaim)));
+}
+
+#[test]
+fn test_is_claim_valid_with_removed_key() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, _identity_owner) = create_identity(&env);
+    let claim_key_pair = add_claim_key(&env, &client, &owner);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &client, &claim_key_pair, &identity_client.address, 1010101, &data, 0, 0);
+    client.remove_key(&owner, &BytesN::from_array(&env, claim_key_pair.public.as_bytes()), &3);
+
+    let is_claim_valid_result = client.try_is_claim_valid(&identity_client.address, &1010101, &signature, &data, &0, &0);
+    assert_eq!(is_claim_valid_result, Err(Ok(OnChainIdError::IssuerKeyNotAuthorized)));
+    assert_eq!(client.try_is_claim_valid(&identity_client.address, &1010101, &signature.slice(..16), &data, &0, &0), Err(Ok(OnChainIdError::InvalidSignature)));
+}
+
+#[test]
+fn test_claim_validity() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, _identity_owner) = create_identity(&env);
+    let claim_key_pair = add_claim_key(&env, &client, &owner);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &client, &claim_key_pair, &identity_client.address, 1010101, &data, 2_000, 3_000);
+    assert!(!client.is_claim_valid(&identity_client.address, &1010101, &signature, &data, &2_000, &3_000));
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_500);
+    assert!(client.is_claim_valid(&identity_client.address, &1010101, &signature, &data, &2_000, &3_000));
+
+    // The validity window is covered by the signature
+    assert!(client.try_is_claim_valid(&identity_client.address, &1010101, &signature, &data, &0, &0).is_err());
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 3_001);
+    assert!(!client.is_claim_valid(&identity_client.address, &1010101, &signature, &data, &2_000, &3_000));
+}
+
+#[test]
+fn test_ecdsa_key_hash() {
+    let env = Env::default();
+
+    // The secp256k1 key of private key 1 has the Ethereum address 0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf
+    let mut private_key = [0u8; 32];
+    private_key[31] = 1;
+    let signing_key = SigningKey::from_bytes(&private_key.into()).unwrap();
+
+    let mut encoded_address = Bytes::from_array(&env, &[0; 12]);
+    encoded_address.extend_from_array(&[
+        0x7e, 0x5f, 0x45, 0x52, 0x09, 0x1a, 0x69, 0x12, 0x5d, 0x5d,
+        0xfc, 0xb7, 0xb8, 0xc2, 0x65, 0x90, 0x29, 0x39, 0x5b, 0xdf,
+    ]);
+    assert_eq!(ecdsa_key(&env, &signing_key), env.crypto().keccak256(&encoded_address).to_bytes());
+}
+
+#[test]
+fn test_is_claim_valid_with_ecdsa_key() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, _identity_owner) = create_identity(&env);
+
+    let signing_key = SigningKey::random(&mut k256::elliptic_curve::rand_core::OsRng);
+    let key = ecdsa_key(&env, &signing_key);
+    client.add_key(&owner, &key, &3, &ECDSA_KEY_TYPE);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_ecdsa_claim(&env, &client, &key, &signing_key, &identity_client.address, 1010101, &data);
+    assert!(client.is_claim_valid(&identity_client.address, &1010101, &signature, &data, &0, &0));
+
+    // A claim signed by another secp256k1 key is not valid
+    let other_signing_key = SigningKey::random(&mut k256::elliptic_curve::rand_core::OsRng);
+    let other_signature = sign_ecdsa_claim(&env, &client, &key, &other_signing_key, &identity_client.address, 1010101, &data);
+    assert!(!client.is_claim_valid(&identity_client.address, &1010101, &other_signature, &data, &0, &0));
+
+    let truncated_signature = signature.slice(..96);
+    assert_eq!(client.try_is_claim_valid(&identity_client.address, &1010101, &truncated_signature, &data, &0, &0), Err(Ok(OnChainIdError::InvalidSignature)));
+}
+
+#[test]
+fn test_is_claim_valid_with_unsupported_key_type() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, _identity_owner) = create_identity(&env);
+
+    let key = BytesN::random(&env);
+    client.add_key(&owner, &key, &3, &RSA_KEY_TYPE);
+
+    let mut signature: Bytes = key.into();
+    signature.extend_from_array(&[0; 64]);
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let is_claim_valid_result = client.try_is_claim_valid(&identity_client.address, &1010101, &signature, &data, &0, &0);
+    assert_eq!(is_claim_valid_result, Err(Ok(OnChainIdError::UnsupportedKeyType)));
+}
+
+#[test]
+fn test_is_claim_valid_with_secp256r1_key() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, _identity_owner) = create_identity(&env);
+
+    let signing_key = P256SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng);
+    let key = client.add_secp256r1_key(&owner, &secp256r1_public_key(&env, &signing_key), &3);
+
+    // Signature of sha256 of the claim message, as a 64 bytes r || s signature with a low s
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let message: std::vec::Vec<u8> = client.claim_message(&identity_client.address, &1010101, &data, &0, &0).iter().collect();
+    let p256_signature: P256Signature = signing_key.sign(&message);
+    let p256_signature = p256_signature.normalize_s().unwrap_or(p256_signature);
+    let mut signature: Bytes = key.into();
+    signature.extend_from_slice(&p256_signature.to_bytes());
+
+    assert!(client.is_claim_valid(&identity_client.address, &1010101, &signature, &data, &0, &0));
+}
+
+#[test]
+fn test_revoke_claim_by_signature() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, _identity_owner) = create_identity(&env);
+    let claim_key_pair = add_claim_key(&env, &client, &owner);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &client, &claim_key_pair, &identity_client.address, 1010101, &data, 0, 0);
+    assert!(!client.is_claim_revoked(&signature));
+
+    // CLAIM keys sign claims, only MANAGEMENT keys revoke them
+    let claim_signer = Address::generate(&env);
+    client.add_key(&owner, &address_to_key(&env, &claim_signer), &3, &ED25519_KEY_TYPE);
+    assert_eq!(client.try_revoke_claim_by_signature(&claim_signer, &signature), Err(Ok(OnChainIdError::Unauthorized)));
+
+    client.revoke_claim_by_signature(&owner, &signature);
+    let signature_hash = env.crypto().keccak256(&signature).to_bytes();
+    assert_eq!(
+        last_events(&env, 1),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "ClaimRevoked"), signature_hash).into_val(&env), signature.clone().into_val(&env))]
+    );
+
+    assert!(client.is_claim_revoked(&signature));
+    assert!(!client.is_claim_valid(&identity_client.address, &1010101, &signature, &data, &0, &0));
+    assert_eq!(client.try_revoke_claim_by_signature(&owner, &signature), Err(Ok(OnChainIdError::ClaimAlreadyRevoked)));
+}
+
+#[test]
+fn test_revoke_claim() {
+    let env = Env::default();
+    let (client, owner) = create_claim_issuer(&env);
+    let (identity_client, identity_owner) = create_identity(&env);
+    let claim_key_pair = add_claim_key(&env, &client, &owner);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &client, &claim_key_pair, &identity_client.address, 1010101, &data, 0, 0);
+    let issuer = client.address.clone();
+    let claim_id = identity_client.add_claim(&identity_owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    // The signature is read from the identity holding the claim
+    assert!(client.revoke_claim(&owner, &claim_id, &identity_client.address));
+    assert!(client.is_claim_revoked(&signature));
+    assert_eq!(client.try_revoke_claim(&owner, &BytesN::random(&env), &identity_client.address), Err(Ok(OnChainIdError::NoClaimFound)));
+
+    // A revoked claim cannot be added again
+    let add_claim_result = identity_client.try_add_claim(&identity_owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::InvalidClaim)));
+}
@@ -0,0 +1,120 @@
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Env, String, Vec};
+use onchain_id::{claim_topics_registry::IClaimTopicsRegistry, storage::extend_instance, structs::ClaimTopic};
+
+use crate::{error::ClaimTopicsRegistryError, events};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    ClaimTopics,
+}
+
+/**
+ * Registry of the claim topics an identity must hold a valid claim of to be verified, mirroring the
+ * ClaimTopicsRegistry contract of T-REX. Each required topic is registered with its name and the
+ * scheme of its claims, as in the catalogue of src/contracts/claimTopics.json.
+ */
+#[contract]
+pub struct ClaimTopicsRegistry;
+
+#[contractimpl]
+impl ClaimTopicsRegistry {
+    /**
+    * Initializes the registry with its admin and no required claim topic.
+    * Can only be called once, right after the deployment of the registry.
+    * @param _admin address managing the required claim topics, which must authorize the initialization
+    */
+    pub fn initialize(e: Env, admin: Address) -> Result<(), ClaimTopicsRegistryError> {
+        if e.storage().instance().has(&DataKey::Admin) {
+            return Err(ClaimTopicsRegistryError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::ClaimTopics, &Vec::<ClaimTopic>::new(&e));
+        extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Adds a claim topic to the required claim topics.
+    * MUST only be done by the admin of the registry.
+    * @param _topic the topic of the claims, which must not be required yet
+    * @param _name the name of the topic, e.g. COUNTRY
+    * @param _scheme the name of the scheme of the claims of the topic, e.g. STRING
+    */
+    pub fn add_claim_topic(e: Env, topic: u128, name: String, scheme: String) -> Result<(), ClaimTopicsRegistryError> {
+        get_admin(&e)?.require_auth();
+
+        let mut claim_topics = get_claim_topics(&e)?;
+        if claim_topics.iter().any(|claim_topic| claim_topic.topic == topic) {
+            return Err(ClaimTopicsRegistryError::ClaimTopicAlreadyRequired);
+        }
+
+        let claim_topic = ClaimTopic { topic, name, scheme };
+        claim_topics.push_back(claim_topic.clone());
+        set_claim_topics(&e, &claim_topics);
+        events::claim_topic_added(&e, &claim_topic);
+        Ok(())
+    }
+
+    /**
+    * Removes a claim topic from the required claim topics.
+    * MUST only be done by the admin of the registry.
+    * @param _topic the topic of the claims, which must be required
+    */
+    pub fn remove_claim_topic(e: Env, topic: u128) -> Result<(), ClaimTopicsRegistryError> {
+        get_admin(&e)?.require_auth();
+
+        let mut claim_topics = get_claim_topics(&e)?;
+        let topic_index = claim_topics.iter().position(|claim_topic| claim_topic.topic == topic)
+            .ok_or(ClaimTopicsRegistryError::ClaimTopicNotRequired)?;
+
+        claim_topics.remove(topic_index as u32);
+        set_claim_topics(&e, &claim_topics);
+        events::claim_topic_removed(&e, topic);
+        Ok(())
+    }
+
+    /**
+    * Returns the admin of the registry.
+    */
+    pub fn get_admin(e: Env) -> Result<Address, ClaimTopicsRegistryError> {
+        get_admin(&e)
+    }
+}
+
+#[contractimpl]
+impl IClaimTopicsRegistry for ClaimTopicsRegistry {
+    /**
+     * See {IClaimTopicsRegistry-get_claim_topics}.
+     */
+    fn get_claim_topics(e: Env) -> Vec<u128> {
+        let mut topics = vec![&e];
+        for claim_topic in get_claim_topics(&e).unwrap_or(vec![&e]) {
+            topics.push_back(claim_topic.topic);
+        }
+        topics
+    }
+
+    /**
+     * See {IClaimTopicsRegistry-get_claim_topics_metadata}.
+     */
+    fn get_claim_topics_metadata(e: Env) -> Vec<ClaimTopic> {
+        get_claim_topics(&e).unwrap_or(vec![&e])
+    }
+}
+
+fn get_admin(e: &Env) -> Result<Address, ClaimTopicsRegistryError> {
+    e.storage().instance().get(&DataKey::Admin).ok_or(ClaimTopicsRegistryError::NotInitialized)
+}
+
+fn get_claim_topics(e: &Env) -> Result<Vec<ClaimTopic>, ClaimTopicsRegistryError> {
+    e.storage().instance().get(&DataKey::ClaimTopics).ok_or(ClaimTopicsRegistryError::NotInitialized)
+}
+
+fn set_claim_topics(e: &Env, claim_topics: &Vec<ClaimTopic>) {
+    e.storage().instance().set(&DataKey::ClaimTopics, claim_topics);
+    extend_instance(e);
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ClaimTopicsRegistryError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    ClaimTopicAlreadyRequired = 3,
+    ClaimTopicNotRequired = 4
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{Env, Symbol};
+
+use onchain_id::structs::ClaimTopic;
+
+/**
+ * Emitted when a claim topic is added to the required claim topics.
+ * Topics: ("ClaimTopicAdded", topic). Data: ClaimTopic.
+ */
+pub(crate) fn claim_topic_added(e: &Env, claim_topic: &ClaimTopic) {
+    e.events().publish((Symbol::new(e, "ClaimTopicAdded"), claim_topic.topic), claim_topic.clone());
+}
+
+/**
+ * Emitted when a claim topic is removed from the required claim topics.
+ * Topics: ("ClaimTopicRemoved", topic). Data: ().
+ */
+pub(crate) fn claim_topic_removed(e: &Env, topic: u128) {
+    e.events().publish((Symbol::new(e, "ClaimTopicRemoved"), topic), ());
+}
@@ -0,0 +1,7 @@
+#![no_std]
+#![allow(clippy::too_many_arguments)]
+
+pub mod claim_topics_registry;
+pub mod error;
+pub mod events;
+mod test;
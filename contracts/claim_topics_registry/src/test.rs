@@ -0,0 +1,112 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{error::ClaimTopicsRegistryError, claim_topics_registry::{ClaimTopicsRegistry, ClaimTopicsRegistryClient}};
+use onchain_id::{claim_topics_registry::{COUNTRY, STANDARD_CLAIM_TOPICS}, structs::ClaimTopic};
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, Env, IntoVal, String, Symbol, Val, Vec};
+use std::vec::Vec as StdVec;
+
+// The catalogue of the claim topics used off-chain, which the registry and STANDARD_CLAIM_TOPICS must not drift from.
+const CLAIM_TOPICS_JSON: &str = include_str!("../../../src/contracts/claimTopics.json");
+const CONSTANTS_JS: &str = include_str!("../../../src/contracts/constants.js");
+
+fn create_registry(env: &Env) -> ClaimTopicsRegistryClient<'_> {
+    env.mock_all_auths();
+    let client = ClaimTopicsRegistryClient::new(env, &env.register_contract(None, ClaimTopicsRegistry));
+    client.initialize(&Address::generate(env));
+    client
+}
+
+// Loads the topics of the catalogue, with the name of their scheme, which is either a string or an object with a name.
+fn catalogue_topics() -> StdVec<(u128, std::string::String, std::string::String)> {
+    let catalogue: serde_json::Map<std::string::String, serde_json::Value> = serde_json::from_str(CLAIM_TOPICS_JSON).unwrap();
+    catalogue.values().map(|topic| {
+        let scheme = match &topic["scheme"] {
+            serde_json::Value::String(scheme) => scheme.clone(),
+            scheme => scheme["name"].as_str().unwrap().into(),
+        };
+        (topic["id"].as_u64().unwrap().into(), topic["name"].as_str().unwrap().into(), scheme)
+    }).collect()
+}
+
+// Returns the last event published in the test environment.
+fn last_event(env: &Env) -> Vec<(Address, Vec<Val>, Val)> {
+    let events = env.events().all();
+    events.slice(events.len() - 1..)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let client = create_registry(&env);
+
+    assert_eq!(client.get_claim_topics(), vec![&env]);
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env)),
+        Err(Ok(ClaimTopicsRegistryError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_add_and_remove_claim_topic() {
+    let env = Env::default();
+    let client = create_registry(&env);
+    let name = String::from_str(&env, "COUNTRY");
+    let scheme = String::from_str(&env, "STRING");
+
+    client.add_claim_topic(&COUNTRY, &name, &scheme);
+    assert_eq!(env.auths()[0].0, client.get_admin());
+    let claim_topic = ClaimTopic { topic: COUNTRY, name: name.clone(), scheme: scheme.clone() };
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "ClaimTopicAdded"), COUNTRY).into_val(&env), claim_topic.into_val(&env))]
+    );
+    assert_eq!(client.get_claim_topics(), vec![&env, COUNTRY]);
+    assert_eq!(client.get_claim_topics_metadata(), vec![&env, claim_topic]);
+    assert_eq!(
+        client.try_add_claim_topic(&COUNTRY, &name, &scheme),
+        Err(Ok(ClaimTopicsRegistryError::ClaimTopicAlreadyRequired))
+    );
+
+    client.remove_claim_topic(&COUNTRY);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "ClaimTopicRemoved"), COUNTRY).into_val(&env), ().into_val(&env))]
+    );
+    assert_eq!(client.get_claim_topics(), vec![&env]);
+    assert_eq!(
+        client.try_remove_claim_topic(&COUNTRY),
+        Err(Ok(ClaimTopicsRegistryError::ClaimTopicNotRequired))
+    );
+}
+
+#[test]
+fn test_standard_claim_topics_match_catalogue() {
+    let mut expected = catalogue_topics();
+    let mut standard: StdVec<_> = STANDARD_CLAIM_TOPICS.iter().map(|(topic, name, scheme)| (*topic, (*name).into(), (*scheme).into())).collect();
+    expected.sort();
+    standard.sort();
+    assert_eq!(standard, expected);
+
+    // Each topic is also exported by constants.js with the same id
+    for (topic, name, _) in STANDARD_CLAIM_TOPICS {
+        assert!(CONSTANTS_JS.contains(&std::format!("{}: {}n,", name, topic)), "{} is not exported by constants.js", name);
+    }
+}
+
+#[test]
+fn test_register_catalogue() {
+    let env = Env::default();
+    let client = create_registry(&env);
+
+    let topics = catalogue_topics();
+    for (topic, name, scheme) in topics.iter() {
+        client.add_claim_topic(topic, &String::from_str(&env, name), &String::from_str(&env, scheme));
+    }
+
+    let registered: StdVec<u128> = client.get_claim_topics().iter().collect();
+    assert_eq!(registered, topics.iter().map(|(topic, _, _)| *topic).collect::<StdVec<_>>());
+    for (claim_topic, (topic, name, scheme)) in client.get_claim_topics_metadata().iter().zip(topics.iter()) {
+        assert_eq!(claim_topic, ClaimTopic { topic: *topic, name: String::from_str(&env, name), scheme: String::from_str(&env, scheme) });
+    }
+}
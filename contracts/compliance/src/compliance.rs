@@ -0,0 +1,415 @@
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, vec, Address, Env, Vec};
+use onchain_id::{compliance::ICompliance, identity_registry::IdentityRegistryClient, storage::{extend_instance, get_persistent, set_persistent}, verifier};
+
+use crate::{error::ComplianceError, events};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    IdentityRegistry,
+    Token,
+    Modules,
+    Holders,
+    Balance(Address),
+    IdentityBalance(Address),
+    BalanceIdentity(Address),
+    Locks(Address),
+}
+
+/**
+ * Rules enforced by the compliance on the transfers and the mints of the token.
+ *
+ * MaxHolders: maximum number of identities holding tokens
+ * CountryAllowList: ISO-3166 numeric country codes the recipients must be registered with
+ * CountryDenyList: ISO-3166 numeric country codes the recipients must not be registered with
+ * MaxBalance: maximum number of tokens held by an identity, over all its wallets
+ * TimeLock: number of seconds the received tokens cannot be transferred for
+ * RequiredClaims: claim topics, e.g. COUNTRY, the identities of the recipients must hold a valid claim of,
+ *  issued by one of the trusted claim issuers. Recipients not registered in the identity registry are rejected
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ComplianceModule {
+    MaxHolders(u32),
+    CountryAllowList(Vec<u32>),
+    CountryDenyList(Vec<u32>),
+    MaxBalance(i128),
+    TimeLock(u64),
+    RequiredClaims(Vec<u128>, Vec<Address>),
+}
+
+/**
+ * Tokens received by a wallet which cannot be transferred before `unlock_time`.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Lock {
+    pub amount: i128,
+    pub unlock_time: u64,
+}
+
+/**
+ * Compliance of a permissioned token, mirroring the ModularCompliance contract of ERC-3643. The admin
+ * plugs the modules enforced on the transfers and the mints of the bound token. The compliance keeps
+ * track of the balances of the wallets and of their identities from the hooks called by the token, and
+ * reads the identities and the countries of the wallets from the identity registry. The wallets which
+ * are not registered are their own identity. The balance of a wallet stays credited to the identity
+ * the wallet had when it received its first tokens until the wallet is emptied, even if the wallet is
+ * then moved to another identity or deleted from the identity registry.
+ * The token must be bound before any token is minted.
+ */
+#[contract]
+pub struct Compliance;
+
+#[contractimpl]
+impl Compliance {
+    /**
+    * Initializes the compliance with its admin and the identity registry of the token.
+    * Can only be called once, right after the deployment of the compliance.
+    * @param _admin address managing the modules, which must authorize the initialization
+    * @param _identityRegistry the identity registry of the token
+    */
+    pub fn initialize(e: Env, admin: Address, identity_registry: Address) -> Result<(), ComplianceError> {
+        if e.storage().instance().has(&DataKey::Admin) {
+            return Err(ComplianceError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::IdentityRegistry, &identity_registry);
+        e.storage().instance().set(&DataKey::Modules, &Vec::<ComplianceModule>::new(&e));
+        extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Binds the token to the compliance, which only accepts the hooks called by this token.
+    * MUST only be done by the admin of the compliance, before any token is minted.
+    * @param _token the permissioned token
+    */
+    pub fn bind_token(e: Env, token: Address) -> Result<(), ComplianceError> {
+        get_admin(&e)?.require_auth();
+
+        if e.storage().instance().has(&DataKey::Token) {
+            return Err(ComplianceError::TokenAlreadyBound);
+        }
+
+        e.storage().instance().set(&DataKey::Token, &token);
+        extend_instance(&e);
+        events::token_bound(&e, &token);
+        Ok(())
+    }
+
+    /**
+    * Adds a module to the compliance. Only one module of each kind can be added.
+    * MUST only be done by the admin of the compliance.
+    * @param _module the module and its configuration
+    */
+    pub fn add_module(e: Env, module: ComplianceModule) -> Result<(), ComplianceError> {
+        get_admin(&e)?.require_auth();
+
+        let mut modules = get_modules(&e);
+        if modules.iter().any(|added_module| core::mem::discriminant(&added_module) == core::mem::discriminant(&module)) {
+            return Err(ComplianceError::ModuleAlreadyAdded);
+        }
+
+        modules.push_back(module.clone());
+        set_modules(&e, &modules);
+        events::module_added(&e, &module);
+        Ok(())
+    }
+
+    /**
+    * Removes a module from the compliance. A module is reconfigured by removing it then adding it again.
+    * MUST only be done by the admin of the compliance.
+    * @param _module the module and its configuration, as added
+    */
+    pub fn remove_module(e: Env, module: ComplianceModule) -> Result<(), ComplianceError> {
+        get_admin(&e)?.require_auth();
+
+        let mut modules = get_modules(&e);
+        let module_index = modules.first_index_of(&module).ok_or(ComplianceError::ModuleNotAdded)?;
+
+        modules.remove(module_index);
+        set_modules(&e, &modules);
+        events::module_removed(&e, &module);
+        Ok(())
+    }
+
+    /**
+    * Returns the modules of the compliance, in the order they were added.
+    */
+    pub fn get_modules(e: Env) -> Vec<ComplianceModule> {
+        get_modules(&e)
+    }
+
+    /**
+    * Returns the number of identities holding tokens.
+    */
+    pub fn get_holder_count(e: Env) -> u32 {
+        e.storage().instance().get(&DataKey::Holders).unwrap_or(0)
+    }
+
+    /**
+    * Returns the number of tokens held by `identity` over all its wallets.
+    */
+    pub fn get_identity_balance(e: Env, identity: Address) -> i128 {
+        get_persistent(&e, &DataKey::IdentityBalance(identity)).unwrap_or(0)
+    }
+
+    /**
+    * Returns the number of tokens of `wallet` which cannot be transferred yet.
+    */
+    pub fn get_locked_balance(e: Env, wallet: Address) -> i128 {
+        locked_balance(&e, &wallet)
+    }
+
+    /**
+    * Returns the token bound to the compliance.
+    */
+    pub fn get_token(e: Env) -> Result<Address, ComplianceError> {
+        get_token(&e)
+    }
+
+    /**
+    * Returns the identity registry of the token.
+    */
+    pub fn get_identity_registry(e: Env) -> Result<Address, ComplianceError> {
+        get_identity_registry(&e)
+    }
+
+    /**
+    * Returns the admin of the compliance.
+    */
+    pub fn get_admin(e: Env) -> Result<Address, ComplianceError> {
+        get_admin(&e)
+    }
+}
+
+#[contractimpl]
+impl ICompliance for Compliance {
+    /**
+     * See {ICompliance-can_transfer}.
+     */
+    fn can_transfer(e: Env, from: Address, to: Address, amount: i128) -> bool {
+        check_modules(&e, Some(&from), &to, amount)
+    }
+
+    /**
+     * See {ICompliance-can_create}.
+     */
+    fn can_create(e: Env, to: Address, amount: i128) -> bool {
+        check_modules(&e, None, &to, amount)
+    }
+
+    /**
+     * See {ICompliance-transferred}.
+     */
+    fn transferred(e: Env, from: Address, to: Address, amount: i128) {
+        require_token(&e);
+
+        decrease_balance(&e, &from, amount);
+        increase_balance(&e, &to, amount);
+    }
+
+    /**
+     * See {ICompliance-created}.
+     */
+    fn created(e: Env, to: Address, amount: i128) {
+        require_token(&e);
+
+        increase_balance(&e, &to, amount);
+    }
+
+    /**
+     * See {ICompliance-destroyed}.
+     */
+    fn destroyed(e: Env, from: Address, amount: i128) {
+        require_token(&e);
+
+        decrease_balance(&e, &from, amount);
+    }
+}
+
+fn get_admin(e: &Env) -> Result<Address, ComplianceError> {
+    e.storage().instance().get(&DataKey::Admin).ok_or(ComplianceError::NotInitialized)
+}
+
+fn get_identity_registry(e: &Env) -> Result<Address, ComplianceError> {
+    e.storage().instance().get(&DataKey::IdentityRegistry).ok_or(ComplianceError::NotInitialized)
+}
+
+fn get_token(e: &Env) -> Result<Address, ComplianceError> {
+    e.storage().instance().get(&DataKey::Token).ok_or(ComplianceError::TokenNotBound)
+}
+
+fn get_modules(e: &Env) -> Vec<ComplianceModule> {
+    e.storage().instance().get(&DataKey::Modules).unwrap_or(vec![e])
+}
+
+fn set_modules(e: &Env, modules: &Vec<ComplianceModule>) {
+    e.storage().instance().set(&DataKey::Modules, modules);
+    extend_instance(e);
+}
+
+/**
+ * Requires the authorization of the bound token, panicking if no token is bound.
+ */
+fn require_token(e: &Env) {
+    match get_token(e) {
+        Ok(token) => token.require_auth(),
+        Err(error) => panic_with_error!(e, error),
+    }
+}
+
+/**
+ * Returns the identity `wallet` is registered with in the identity registry, if any.
+ */
+fn registered_identity(e: &Env, wallet: &Address) -> Option<Address> {
+    get_identity_registry(e).ok()
+        .and_then(|identity_registry| IdentityRegistryClient::new(e, &identity_registry).get_identity(wallet))
+}
+
+/**
+ * Returns the identity `wallet` is registered with, or the wallet itself if it is not registered.
+ */
+fn identity_of(e: &Env, wallet: &Address) -> Address {
+    registered_identity(e, wallet).unwrap_or(wallet.clone())
+}
+
+/**
+ * Returns the identity the balance of `wallet` is credited to: the identity recorded when the wallet
+ * received its first tokens while it holds tokens, or its current identity otherwise.
+ */
+fn balance_identity(e: &Env, wallet: &Address) -> Address {
+    let balance: i128 = get_persistent(e, &DataKey::Balance(wallet.clone())).unwrap_or(0);
+    if balance > 0 {
+        if let Some(identity) = get_persistent(e, &DataKey::BalanceIdentity(wallet.clone())) {
+            return identity;
+        }
+    }
+    identity_of(e, wallet)
+}
+
+/**
+ * Returns TRUE if every module allows `amount` tokens to be transferred from `from` to `to`, or to be
+ * minted to `to` when `from` is None.
+ */
+fn check_modules(e: &Env, from: Option<&Address>, to: &Address, amount: i128) -> bool {
+    if amount < 0 {
+        return false;
+    }
+    let modules = get_modules(e);
+    if modules.is_empty() {
+        return true;
+    }
+
+    let to_identity = balance_identity(e, to);
+    let from_identity = from.map(|from| balance_identity(e, from));
+    let same_identity = from_identity.as_ref() == Some(&to_identity);
+    let to_identity_balance: i128 = get_persistent(e, &DataKey::IdentityBalance(to_identity)).unwrap_or(0);
+
+    modules.iter().all(|module| match module {
+        ComplianceModule::MaxHolders(max_holders) => {
+            if same_identity || to_identity_balance > 0 || amount == 0 {
+                return true;
+            }
+            let from_leaves = from_identity.as_ref().is_some_and(|from_identity| {
+                get_persistent::<i128>(e, &DataKey::IdentityBalance(from_identity.clone())).unwrap_or(0) <= amount
+            });
+            let holders: u32 = e.storage().instance().get(&DataKey::Holders).unwrap_or(0);
+            holders + 1 - from_leaves as u32 <= max_holders
+        }
+        ComplianceModule::CountryAllowList(countries) => {
+            investor_country(e, to).is_some_and(|country| countries.contains(country))
+        }
+        ComplianceModule::CountryDenyList(countries) => {
+            !investor_country(e, to).is_some_and(|country| countries.contains(country))
+        }
+        ComplianceModule::MaxBalance(max_balance) => {
+            same_identity || to_identity_balance + amount <= max_balance
+        }
+        ComplianceModule::TimeLock(_) => from.is_none_or(|from| {
+            get_persistent::<i128>(e, &DataKey::Balance(from.clone())).unwrap_or(0) - locked_balance(e, from) >= amount
+        }),
+        ComplianceModule::RequiredClaims(topics, trusted_issuers) => registered_identity(e, to)
+            .is_some_and(|identity| verifier::verify(e, &identity, &topics, &trusted_issuers).verified),
+    })
+}
+
+fn investor_country(e: &Env, wallet: &Address) -> Option<u32> {
+    get_identity_registry(e).ok()
+        .and_then(|identity_registry| IdentityRegistryClient::new(e, &identity_registry).get_investor_country(wallet))
+}
+
+/**
+ * Returns the tokens of `wallet` whose lock has not expired yet.
+ */
+fn locked_balance(e: &Env, wallet: &Address) -> i128 {
+    let now = e.ledger().timestamp();
+    get_persistent::<Vec<Lock>>(e, &DataKey::Locks(wallet.clone())).unwrap_or(vec![e]).iter()
+        .filter(|lock| lock.unlock_time > now)
+        .map(|lock| lock.amount)
+        .sum()
+}
+
+/**
+ * Records `amount` tokens received by `wallet`, locking them if a TimeLock module is added.
+ */
+fn increase_balance(e: &Env, wallet: &Address, amount: i128) {
+    let identity = balance_identity(e, wallet);
+    let balance_key = DataKey::Balance(wallet.clone());
+    let balance: i128 = get_persistent(e, &balance_key).unwrap_or(0);
+    set_persistent(e, &balance_key, &(balance + amount));
+    if balance == 0 {
+        set_persistent(e, &DataKey::BalanceIdentity(wallet.clone()), &identity);
+    }
+
+    let identity_balance_key = DataKey::IdentityBalance(identity);
+    let identity_balance: i128 = get_persistent(e, &identity_balance_key).unwrap_or(0);
+    set_persistent(e, &identity_balance_key, &(identity_balance + amount));
+    if identity_balance == 0 && amount > 0 {
+        let holders: u32 = e.storage().instance().get(&DataKey::Holders).unwrap_or(0);
+        e.storage().instance().set(&DataKey::Holders, &(holders + 1));
+    }
+
+    let lock_period = get_modules(e).iter().find_map(|module| match module {
+        ComplianceModule::TimeLock(lock_period) => Some(lock_period),
+        _ => None,
+    });
+    if let Some(lock_period) = lock_period {
+        let now = e.ledger().timestamp();
+        let locks_key = DataKey::Locks(wallet.clone());
+        let mut locks: Vec<Lock> = vec![e];
+        for lock in get_persistent::<Vec<Lock>>(e, &locks_key).unwrap_or(vec![e]).iter().filter(|lock| lock.unlock_time > now) {
+            locks.push_back(lock);
+        }
+        locks.push_back(Lock { amount, unlock_time: now + lock_period });
+        set_persistent(e, &locks_key, &locks);
+    }
+}
+
+/**
+ * Records `amount` tokens sent or burnt by `wallet`, debited from the identity they were credited to.
+ */
+fn decrease_balance(e: &Env, wallet: &Address, amount: i128) {
+    let identity = balance_identity(e, wallet);
+    let balance_key = DataKey::Balance(wallet.clone());
+    let balance: i128 = get_persistent(e, &balance_key).unwrap_or(0);
+    let identity_balance_key = DataKey::IdentityBalance(identity);
+    let identity_balance: i128 = get_persistent(e, &identity_balance_key).unwrap_or(0);
+    if balance < amount || identity_balance < amount {
+        panic_with_error!(e, ComplianceError::InsufficientBalance);
+    }
+
+    set_persistent(e, &balance_key, &(balance - amount));
+    if balance == amount {
+        e.storage().persistent().remove(&DataKey::BalanceIdentity(wallet.clone()));
+    }
+    set_persistent(e, &identity_balance_key, &(identity_balance - amount));
+    if identity_balance == amount && amount > 0 {
+        let holders: u32 = e.storage().instance().get(&DataKey::Holders).unwrap_or(0);
+        e.storage().instance().set(&DataKey::Holders, &(holders - 1));
+    }
+}
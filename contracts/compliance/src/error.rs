@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ComplianceError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    TokenAlreadyBound = 3,
+    TokenNotBound = 4,
+    ModuleAlreadyAdded = 5,
+    ModuleNotAdded = 6,
+    InsufficientBalance = 7
+}
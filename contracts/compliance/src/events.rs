@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::compliance::ComplianceModule;
+
+/**
+ * Emitted when the token is bound to the compliance.
+ * Topics: ("TokenBound", token). Data: ().
+ */
+pub(crate) fn token_bound(e: &Env, token: &Address) {
+    e.events().publish((Symbol::new(e, "TokenBound"), token.clone()), ());
+}
+
+/**
+ * Emitted when a module is added to the compliance.
+ * Topics: ("ModuleAdded",). Data: ComplianceModule.
+ */
+pub(crate) fn module_added(e: &Env, module: &ComplianceModule) {
+    e.events().publish((Symbol::new(e, "ModuleAdded"),), module.clone());
+}
+
+/**
+ * Emitted when a module is removed from the compliance.
+ * Topics: ("ModuleRemoved",). Data: ComplianceModule.
+ */
+pub(crate) fn module_removed(e: &Env, module: &ComplianceModule) {
+    e.events().publish((Symbol::new(e, "ModuleRemoved"),), module.clone());
+}
@@ -0,0 +1,246 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{compliance::{Compliance, ComplianceClient, ComplianceModule}, error::ComplianceError};
+use claim_issuer::claim_issuer::{ClaimIssuer, ClaimIssuerClient};
+use identity::identity::Identity;
+use identity_registry::identity_registry::{IdentityRegistry, IdentityRegistryClient};
+use onchain_id::{claim_topics_registry::COUNTRY, testutils::{add_claim, last_event, register_claim_issuer, register_identity}};
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Env, IntoVal, Symbol};
+
+// Registers a compliance bound to a token, with an identity registry and an agent registering the investors.
+fn create_compliance(env: &Env) -> (ComplianceClient<'_>, IdentityRegistryClient<'_>, Address) {
+    env.mock_all_auths();
+    let identity_registry = IdentityRegistryClient::new(env, &env.register_contract(None, IdentityRegistry));
+    identity_registry.initialize(&Address::generate(env), &Address::generate(env), &Address::generate(env));
+    let agent = Address::generate(env);
+    identity_registry.add_agent(&agent);
+
+    let compliance = ComplianceClient::new(env, &env.register_contract(None, Compliance));
+    compliance.initialize(&Address::generate(env), &identity_registry.address);
+    compliance.bind_token(&Address::generate(env));
+    (compliance, identity_registry, agent)
+}
+
+// Registers a new wallet of `identity` in the identity registry.
+fn register_wallet(env: &Env, identity_registry: &IdentityRegistryClient<'_>, agent: &Address, identity: &Address, country: u32) -> Address {
+    let wallet = Address::generate(env);
+    identity_registry.register_identity(agent, &wallet, identity, &country);
+    wallet
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let (compliance, identity_registry, _) = create_compliance(&env);
+
+    assert_eq!(compliance.get_identity_registry(), identity_registry.address);
+    assert_eq!(
+        compliance.try_initialize(&Address::generate(&env), &identity_registry.address),
+        Err(Ok(ComplianceError::AlreadyInitialized))
+    );
+    assert_eq!(compliance.try_bind_token(&Address::generate(&env)), Err(Ok(ComplianceError::TokenAlreadyBound)));
+
+    // Without any module, every transfer is compliant
+    assert!(compliance.can_transfer(&Address::generate(&env), &Address::generate(&env), &1_000));
+    assert!(compliance.can_create(&Address::generate(&env), &1_000));
+}
+
+#[test]
+fn test_add_and_remove_module() {
+    let env = Env::default();
+    let (compliance, _, _) = create_compliance(&env);
+    let module = ComplianceModule::MaxHolders(10);
+
+    compliance.add_module(&module);
+    assert_eq!(env.auths()[0].0, compliance.get_admin());
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (compliance.address.clone(), (Symbol::new(&env, "ModuleAdded"),).into_val(&env), module.into_val(&env))]
+    );
+    assert_eq!(compliance.get_modules(), vec![&env, module.clone()]);
+    assert_eq!(compliance.try_add_module(&ComplianceModule::MaxHolders(20)), Err(Ok(ComplianceError::ModuleAlreadyAdded)));
+    assert_eq!(compliance.try_remove_module(&ComplianceModule::MaxHolders(20)), Err(Ok(ComplianceError::ModuleNotAdded)));
+
+    compliance.remove_module(&module);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (compliance.address.clone(), (Symbol::new(&env, "ModuleRemoved"),).into_val(&env), module.into_val(&env))]
+    );
+    assert_eq!(compliance.get_modules(), vec![&env]);
+}
+
+#[test]
+fn test_hooks() {
+    let env = Env::default();
+    let (compliance, identity_registry, agent) = create_compliance(&env);
+    let identity = Address::generate(&env);
+    let wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+    let other_wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+    let unregistered_wallet = Address::generate(&env);
+
+    compliance.created(&wallet, &100);
+    assert_eq!(env.auths()[0].0, compliance.get_token());
+    compliance.transferred(&wallet, &other_wallet, &40);
+    assert_eq!(compliance.get_identity_balance(&identity), 100);
+    assert_eq!(compliance.get_holder_count(), 1);
+
+    // The wallets which are not registered are their own identity
+    compliance.transferred(&other_wallet, &unregistered_wallet, &40);
+    assert_eq!(compliance.get_identity_balance(&identity), 60);
+    assert_eq!(compliance.get_identity_balance(&unregistered_wallet), 40);
+    assert_eq!(compliance.get_holder_count(), 2);
+
+    compliance.destroyed(&wallet, &60);
+    assert_eq!(compliance.get_holder_count(), 1);
+    assert!(compliance.try_destroyed(&wallet, &1).is_err());
+}
+
+#[test]
+fn test_updated_and_deleted_wallets() {
+    let env = Env::default();
+    let (compliance, identity_registry, agent) = create_compliance(&env);
+    let identity = Address::generate(&env);
+    let new_identity = Address::generate(&env);
+    let wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+    let other_wallet = register_wallet(&env, &identity_registry, &agent, &new_identity, 250);
+    compliance.created(&wallet, &100);
+
+    // The balance of a wallet stays credited to its identity when the wallet moves to another identity
+    identity_registry.update_identity(&agent, &wallet, &new_identity);
+    compliance.transferred(&wallet, &other_wallet, &40);
+    assert_eq!(compliance.get_identity_balance(&identity), 60);
+    assert_eq!(compliance.get_identity_balance(&new_identity), 40);
+    assert_eq!(compliance.get_holder_count(), 2);
+
+    // and when the wallet is deleted, so its tokens can still be clawed back
+    identity_registry.delete_identity(&agent, &wallet);
+    compliance.destroyed(&wallet, &60);
+    assert_eq!(compliance.get_identity_balance(&identity), 0);
+    assert_eq!(compliance.get_holder_count(), 1);
+
+    // An emptied wallet is credited to its current identity
+    identity_registry.register_identity(&agent, &wallet, &new_identity, &250);
+    compliance.transferred(&other_wallet, &wallet, &40);
+    assert_eq!(compliance.get_identity_balance(&new_identity), 40);
+    assert_eq!(compliance.get_holder_count(), 1);
+}
+
+#[test]
+fn test_max_holders() {
+    let env = Env::default();
+    let (compliance, identity_registry, agent) = create_compliance(&env);
+    compliance.add_module(&ComplianceModule::MaxHolders(2));
+    let identity = Address::generate(&env);
+    let wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+    let other_wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+    let second_holder = Address::generate(&env);
+    let third_holder = Address::generate(&env);
+
+    compliance.created(&wallet, &100);
+    compliance.created(&second_holder, &100);
+    assert!(!compliance.can_create(&third_holder, &100));
+    assert!(!compliance.can_transfer(&wallet, &third_holder, &50));
+
+    // The wallets of a holder count once, and a holder sending all its tokens is replaced
+    assert!(compliance.can_create(&other_wallet, &100));
+    assert!(compliance.can_transfer(&wallet, &other_wallet, &50));
+    assert!(compliance.can_transfer(&second_holder, &third_holder, &100));
+}
+
+#[test]
+fn test_max_balance() {
+    let env = Env::default();
+    let (compliance, identity_registry, agent) = create_compliance(&env);
+    compliance.add_module(&ComplianceModule::MaxBalance(100));
+    let identity = Address::generate(&env);
+    let wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+    let other_wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+
+    assert!(!compliance.can_create(&wallet, &101));
+    compliance.created(&wallet, &60);
+
+    // The balance of an identity covers all its wallets
+    assert!(!compliance.can_create(&other_wallet, &41));
+    assert!(compliance.can_create(&other_wallet, &40));
+    assert!(compliance.can_transfer(&wallet, &other_wallet, &60));
+    assert!(!compliance.can_transfer(&wallet, &Address::generate(&env), &-1));
+}
+
+#[test]
+fn test_country_lists() {
+    let env = Env::default();
+    let (compliance, identity_registry, agent) = create_compliance(&env);
+    let french_wallet = register_wallet(&env, &identity_registry, &agent, &Address::generate(&env), 250);
+    let german_wallet = register_wallet(&env, &identity_registry, &agent, &Address::generate(&env), 276);
+    let unregistered_wallet = Address::generate(&env);
+
+    let allow_list = ComplianceModule::CountryAllowList(vec![&env, 250]);
+    compliance.add_module(&allow_list);
+    assert!(compliance.can_create(&french_wallet, &100));
+    assert!(!compliance.can_create(&german_wallet, &100));
+    assert!(!compliance.can_transfer(&french_wallet, &unregistered_wallet, &100));
+
+    compliance.remove_module(&allow_list);
+    compliance.add_module(&ComplianceModule::CountryDenyList(vec![&env, 276]));
+    assert!(compliance.can_transfer(&german_wallet, &french_wallet, &100));
+    assert!(!compliance.can_transfer(&french_wallet, &german_wallet, &100));
+    assert!(compliance.can_transfer(&french_wallet, &unregistered_wallet, &100));
+}
+
+#[test]
+fn test_required_claims() {
+    let env = Env::default();
+    let (compliance, identity_registry, agent) = create_compliance(&env);
+    // Each check verifies the claims of the recipient
+    env.budget().reset_unlimited();
+    let (issuer, issuer_owner, key_pair) = register_claim_issuer(&env, ClaimIssuer);
+    let (untrusted_issuer, _, untrusted_key_pair) = register_claim_issuer(&env, ClaimIssuer);
+    let (identity, owner) = register_identity(&env, Identity);
+    let (other_identity, _) = register_identity(&env, Identity);
+    let wallet = register_wallet(&env, &identity_registry, &agent, &identity, 250);
+    let other_wallet = register_wallet(&env, &identity_registry, &agent, &other_identity, 250);
+
+    compliance.add_module(&ComplianceModule::RequiredClaims(vec![&env, COUNTRY], vec![&env, issuer.clone()]));
+    assert!(!compliance.can_create(&wallet, &100));
+
+    // Only the claims of the trusted issuers are accepted
+    add_claim(&env, &identity, &owner, &untrusted_issuer, &untrusted_key_pair, COUNTRY, 0, 0);
+    assert!(!compliance.can_create(&wallet, &100));
+    let signature = add_claim(&env, &identity, &owner, &issuer, &key_pair, COUNTRY, 0, 0);
+    assert!(compliance.can_create(&wallet, &100));
+
+    // Only the claims of the recipient are checked, which must be registered
+    assert!(compliance.can_transfer(&other_wallet, &wallet, &100));
+    assert!(!compliance.can_transfer(&wallet, &other_wallet, &100));
+    assert!(!compliance.can_transfer(&wallet, &Address::generate(&env), &100));
+
+    // A revoked claim is no longer valid
+    ClaimIssuerClient::new(&env, &issuer).revoke_claim_by_signature(&issuer_owner, &signature);
+    assert!(!compliance.can_create(&wallet, &100));
+}
+
+#[test]
+fn test_time_lock() {
+    let env = Env::default();
+    let (compliance, _, _) = create_compliance(&env);
+    compliance.add_module(&ComplianceModule::TimeLock(1_000));
+    let wallet = Address::generate(&env);
+    let other_wallet = Address::generate(&env);
+
+    compliance.created(&wallet, &100);
+    assert_eq!(compliance.get_locked_balance(&wallet), 100);
+    assert!(!compliance.can_transfer(&wallet, &other_wallet, &1));
+
+    env.ledger().set_timestamp(500);
+    compliance.created(&wallet, &50);
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(compliance.get_locked_balance(&wallet), 50);
+    assert!(compliance.can_transfer(&wallet, &other_wallet, &100));
+    assert!(!compliance.can_transfer(&wallet, &other_wallet, &101));
+
+    // The received tokens are locked for the recipient
+    compliance.transferred(&wallet, &other_wallet, &100);
+    assert_eq!(compliance.get_locked_balance(&other_wallet), 100);
+    assert!(!compliance.can_transfer(&other_wallet, &wallet, &1));
+}
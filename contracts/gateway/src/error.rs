@@ -0,0 +1,16 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GatewayError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    SignerAlreadyApproved = 3,
+    SignerNotApproved = 4,
+    SignatureAlreadyRevoked = 5,
+    SignatureNotRevoked = 6,
+    ExpiredSignature = 7,
+    UnapprovedSigner = 8,
+    RevokedSignature = 9
+}
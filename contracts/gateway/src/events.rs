@@ -0,0 +1,33 @@
+use soroban_sdk::{BytesN, Env, Symbol};
+
+/**
+ * Emitted when a signer is approved to authorize identity deployments.
+ * Topics: ("SignerApproved", signer). Data: ().
+ */
+pub(crate) fn signer_approved(e: &Env, signer: &BytesN<32>) {
+    e.events().publish((Symbol::new(e, "SignerApproved"), signer.clone()), ());
+}
+
+/**
+ * Emitted when a signer is no longer approved.
+ * Topics: ("SignerRevoked", signer). Data: ().
+ */
+pub(crate) fn signer_revoked(e: &Env, signer: &BytesN<32>) {
+    e.events().publish((Symbol::new(e, "SignerRevoked"), signer.clone()), ());
+}
+
+/**
+ * Emitted when a deployment approval is revoked.
+ * Topics: ("SignatureRevoked", signature). Data: ().
+ */
+pub(crate) fn signature_revoked(e: &Env, signature: &BytesN<64>) {
+    e.events().publish((Symbol::new(e, "SignatureRevoked"), signature.clone()), ());
+}
+
+/**
+ * Emitted when a revoked deployment approval is approved again.
+ * Topics: ("SignatureApproved", signature). Data: ().
+ */
+pub(crate) fn signature_approved(e: &Env, signature: &BytesN<64>) {
+    e.events().publish((Symbol::new(e, "SignatureApproved"), signature.clone()), ());
+}
@@ -0,0 +1,245 @@
+use soroban_sdk::{contract, contractclient, contractimpl, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
+use onchain_id::storage::{extend_instance, get_persistent, set_persistent};
+
+use crate::{error::GatewayError, events};
+
+/**
+ * Prefix of the message signed by an approved signer to authorize the deployment of an identity.
+ */
+pub const DEPLOYMENT_MESSAGE_PREFIX: &[u8] = b"Authorize ONCHAINID deployment";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    IdFactory,
+    ApprovedSigner(BytesN<32>),
+    RevokedSignature(BytesN<64>),
+}
+
+/**
+ * Functions of the identity factory called by the gateway, which must own the factory.
+ */
+#[contractclient(name = "IdFactoryClient")]
+pub trait IIdFactory {
+    fn create_identity(e: Env, wallet: Address, salt: String) -> Address;
+
+    fn transfer_ownership(e: Env, new_owner: Address);
+}
+
+/**
+ * Gateway of an identity factory, mirroring the Gateway contract of the Solidity implementation.
+ * Anyone can deploy an identity through the gateway with an approval signed off-chain by one of
+ * the ed25519 signers approved by the admin of the gateway. Approvals can expire and be revoked.
+ * The gateway must own the factory, see {IdFactory-transfer_ownership}.
+ */
+#[contract]
+pub struct Gateway;
+
+#[contractimpl]
+impl Gateway {
+    /**
+    * Initializes the gateway with its admin, the factory it deploys identities with and the signers approving the deployments.
+    * Can only be called once, right after the deployment of the gateway.
+    * @param _admin address managing the signers, which must authorize the initialization
+    * @param _idFactory the identity factory, whose ownership must then be transferred to the gateway
+    * @param _signers ed25519 public keys of the signers approving the deployments
+    */
+    pub fn initialize(e: Env, admin: Address, id_factory: Address, signers: Vec<BytesN<32>>) -> Result<(), GatewayError> {
+        if e.storage().instance().has(&DataKey::Admin) {
+            return Err(GatewayError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::IdFactory, &id_factory);
+        extend_instance(&e);
+        for signer in signers {
+            approve_signer(&e, &signer)?;
+        }
+        Ok(())
+    }
+
+    /**
+    * Approves a signer to authorize identity deployments.
+    * MUST only be done by the admin of the gateway.
+    * @param _signer ed25519 public key of the signer
+    */
+    pub fn approve_signer(e: Env, signer: BytesN<32>) -> Result<(), GatewayError> {
+        get_admin(&e)?.require_auth();
+
+        approve_signer(&e, &signer)
+    }
+
+    /**
+    * Revokes the approval of a signer. The deployments it approved can no longer be done.
+    * MUST only be done by the admin of the gateway.
+    * @param _signer ed25519 public key of the signer
+    */
+    pub fn revoke_signer(e: Env, signer: BytesN<32>) -> Result<(), GatewayError> {
+        get_admin(&e)?.require_auth();
+
+        let signer_key = DataKey::ApprovedSigner(signer.clone());
+        if !e.storage().persistent().has(&signer_key) {
+            return Err(GatewayError::SignerNotApproved);
+        }
+
+        e.storage().persistent().remove(&signer_key);
+        events::signer_revoked(&e, &signer);
+        Ok(())
+    }
+
+    /**
+    * Revokes a deployment approval, which can no longer be used to deploy an identity.
+    * MUST only be done by the admin of the gateway.
+    * @param _signature the signature of the approval
+    */
+    pub fn revoke_signature(e: Env, signature: BytesN<64>) -> Result<(), GatewayError> {
+        get_admin(&e)?.require_auth();
+
+        let revoked_key = DataKey::RevokedSignature(signature.clone());
+        if e.storage().persistent().has(&revoked_key) {
+            return Err(GatewayError::SignatureAlreadyRevoked);
+        }
+
+        set_persistent(&e, &revoked_key, &true);
+        events::signature_revoked(&e, &signature);
+        Ok(())
+    }
+
+    /**
+    * Approves again a revoked deployment approval.
+    * MUST only be done by the admin of the gateway.
+    * @param _signature the signature of the approval
+    */
+    pub fn approve_signature(e: Env, signature: BytesN<64>) -> Result<(), GatewayError> {
+        get_admin(&e)?.require_auth();
+
+        let revoked_key = DataKey::RevokedSignature(signature.clone());
+        if !e.storage().persistent().has(&revoked_key) {
+            return Err(GatewayError::SignatureNotRevoked);
+        }
+
+        e.storage().persistent().remove(&revoked_key);
+        events::signature_approved(&e, &signature);
+        Ok(())
+    }
+
+    /**
+    * Deploys an identity for `wallet` with the factory, as approved by an approved signer.
+    * Can be called by anyone holding the approval.
+    * @param _wallet the wallet of the user, registered as the MANAGEMENT key of the identity
+    * @param _salt the salt of the deployment
+    * @param _signatureExpiry ledger timestamp until which the approval can be used, 0 if it does not expire
+    * @param _signer ed25519 public key of the approved signer
+    * @param _signature signature by the signer of the message returned by deployment_message
+    * @return identity Returns the address of the deployed identity
+    */
+    pub fn deploy_identity_with_salt(e: Env, wallet: Address, salt: String, signature_expiry: u64, signer: BytesN<32>, signature: BytesN<64>) -> Result<Address, GatewayError> {
+        if signature_expiry != 0 && signature_expiry < e.ledger().timestamp() {
+            return Err(GatewayError::ExpiredSignature);
+        }
+        if !is_approved_signer(&e, &signer) {
+            return Err(GatewayError::UnapprovedSigner);
+        }
+        if is_signature_revoked(&e, &signature) {
+            return Err(GatewayError::RevokedSignature);
+        }
+
+        let message = deployment_message(&e, &wallet, &salt, signature_expiry);
+        e.crypto().ed25519_verify(&signer, &message, &signature);
+
+        Ok(IdFactoryClient::new(&e, &get_id_factory(&e)?).create_identity(&wallet, &salt))
+    }
+
+    /**
+    * Transfers the ownership of the factory, e.g. to replace the gateway.
+    * MUST only be done by the admin of the gateway.
+    * @param _newOwner the new owner of the factory
+    */
+    pub fn transfer_factory_ownership(e: Env, new_owner: Address) -> Result<(), GatewayError> {
+        get_admin(&e)?.require_auth();
+
+        IdFactoryClient::new(&e, &get_id_factory(&e)?).transfer_ownership(&new_owner);
+        Ok(())
+    }
+
+    /**
+    * Returns the message an approved signer signs to authorize the deployment of an identity:
+    * the "Authorize ONCHAINID deployment" prefix, followed by the network id, i.e. the SHA-256 hash of
+    * the network passphrase, the XDR of the gateway address, of the wallet and of the salt, then the
+    * expiry as 8 big-endian bytes. An approval is thereby only valid on this gateway and network.
+    * @param _wallet the wallet of the user
+    * @param _salt the salt of the deployment
+    * @param _signatureExpiry ledger timestamp until which the approval can be used, 0 if it does not expire
+    * @return message Returns the message to sign
+    */
+    pub fn deployment_message(e: Env, wallet: Address, salt: String, signature_expiry: u64) -> Bytes {
+        deployment_message(&e, &wallet, &salt, signature_expiry)
+    }
+
+    /**
+    * Returns TRUE if `signer` is approved to authorize identity deployments.
+    */
+    pub fn is_approved_signer(e: Env, signer: BytesN<32>) -> bool {
+        is_approved_signer(&e, &signer)
+    }
+
+    /**
+    * Returns TRUE if the deployment approval was revoked.
+    */
+    pub fn is_signature_revoked(e: Env, signature: BytesN<64>) -> bool {
+        is_signature_revoked(&e, &signature)
+    }
+
+    /**
+    * Returns the identity factory of the gateway.
+    */
+    pub fn get_id_factory(e: Env) -> Result<Address, GatewayError> {
+        get_id_factory(&e)
+    }
+
+    /**
+    * Returns the admin of the gateway.
+    */
+    pub fn get_admin(e: Env) -> Result<Address, GatewayError> {
+        get_admin(&e)
+    }
+}
+
+fn get_admin(e: &Env) -> Result<Address, GatewayError> {
+    e.storage().instance().get(&DataKey::Admin).ok_or(GatewayError::NotInitialized)
+}
+
+fn get_id_factory(e: &Env) -> Result<Address, GatewayError> {
+    e.storage().instance().get(&DataKey::IdFactory).ok_or(GatewayError::NotInitialized)
+}
+
+fn approve_signer(e: &Env, signer: &BytesN<32>) -> Result<(), GatewayError> {
+    let signer_key = DataKey::ApprovedSigner(signer.clone());
+    if e.storage().persistent().has(&signer_key) {
+        return Err(GatewayError::SignerAlreadyApproved);
+    }
+
+    set_persistent(e, &signer_key, &true);
+    events::signer_approved(e, signer);
+    Ok(())
+}
+
+fn is_approved_signer(e: &Env, signer: &BytesN<32>) -> bool {
+    get_persistent::<bool>(e, &DataKey::ApprovedSigner(signer.clone())).unwrap_or(false)
+}
+
+fn is_signature_revoked(e: &Env, signature: &BytesN<64>) -> bool {
+    get_persistent::<bool>(e, &DataKey::RevokedSignature(signature.clone())).unwrap_or(false)
+}
+
+fn deployment_message(e: &Env, wallet: &Address, salt: &String, signature_expiry: u64) -> Bytes {
+    let mut message = Bytes::from_slice(e, DEPLOYMENT_MESSAGE_PREFIX);
+    message.append(&e.ledger().network_id().into());
+    message.append(&e.current_contract_address().to_xdr(e));
+    message.append(&wallet.clone().to_xdr(e));
+    message.append(&salt.clone().to_xdr(e));
+    message.append(&Bytes::from_array(e, &signature_expiry.to_be_bytes()));
+    message
+}
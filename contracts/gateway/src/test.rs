@@ -0,0 +1,195 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{error::GatewayError, gateway::{Gateway, GatewayClient}};
+use id_factory::id_factory::{IdFactory, IdFactoryClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, BytesN, Env, String};
+use ed25519_dalek::{Keypair, Signer};
+use rand::thread_rng;
+
+// The identity contract deployed by the factory, built with `stellar contract build` before running the tests.
+mod identity_contract {
+    use soroban_sdk::auth::Context;
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/identity.wasm");
+}
+
+// Registers a gateway owning a factory, with an approved signer whose key pair is returned.
+fn create_gateway(env: &Env) -> (GatewayClient<'_>, IdFactoryClient<'_>, Keypair) {
+    env.mock_all_auths();
+    // Each identity call instantiates the wasm of the identity
+    env.budget().reset_unlimited();
+
+    let factory = IdFactoryClient::new(env, &env.register_contract(None, IdFactory));
+    let wasm_hash = env.deployer().upload_contract_wasm(identity_contract::WASM);
+    factory.initialize(&Address::generate(env), &wasm_hash);
+
+    let gateway = GatewayClient::new(env, &env.register_contract(None, Gateway));
+    factory.transfer_ownership(&gateway.address);
+
+    let signer = Keypair::generate(&mut thread_rng());
+    gateway.initialize(&Address::generate(env), &factory.address, &vec![env, signer_key(env, &signer)]);
+    (gateway, factory, signer)
+}
+
+fn signer_key(env: &Env, key_pair: &Keypair) -> BytesN<32> {
+    BytesN::from_array(env, key_pair.public.as_bytes())
+}
+
+// Signs the deployment message of the gateway with an ed25519 key pair.
+fn sign_deployment(env: &Env, gateway: &GatewayClient<'_>, key_pair: &Keypair, wallet: &Address, salt: &String, signature_expiry: u64) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = gateway.deployment_message(wallet, salt, &signature_expiry).iter().collect();
+    BytesN::from_array(env, &key_pair.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let (gateway, factory, signer) = create_gateway(&env);
+
+    assert_eq!(gateway.get_id_factory(), factory.address);
+    assert_eq!(factory.get_owner(), gateway.address);
+    assert!(gateway.is_approved_signer(&signer_key(&env, &signer)));
+    assert!(!gateway.is_approved_signer(&BytesN::from_array(&env, &[1; 32])));
+    assert_eq!(
+        gateway.try_initialize(&Address::generate(&env), &factory.address, &vec![&env]),
+        Err(Ok(GatewayError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_deploy_identity_with_salt() {
+    let env = Env::default();
+    let (gateway, factory, signer) = create_gateway(&env);
+
+    let wallet = Address::generate(&env);
+    let salt = String::from_str(&env, "alice");
+    let signature = sign_deployment(&env, &gateway, &signer, &wallet, &salt, 0);
+
+    let identity = gateway.deploy_identity_with_salt(&wallet, &salt, &0, &signer_key(&env, &signer), &signature);
+    assert_eq!(identity, factory.get_identity_address(&salt));
+    assert_eq!(factory.get_identity(&wallet), Some(identity));
+
+    // The approval cannot deploy another identity with the same salt
+    assert!(gateway.try_deploy_identity_with_salt(&wallet, &salt, &0, &signer_key(&env, &signer), &signature).is_err());
+
+    // The approval only covers its wallet, salt and expiry
+    let other_wallet = Address::generate(&env);
+    let other_salt = String::from_str(&env, "bob");
+    let other_signature = sign_deployment(&env, &gateway, &signer, &other_wallet, &other_salt, 0);
+    assert!(gateway.try_deploy_identity_with_salt(&Address::generate(&env), &other_salt, &0, &signer_key(&env, &signer), &other_signature).is_err());
+    assert!(gateway.try_deploy_identity_with_salt(&other_wallet, &other_salt, &1_000, &signer_key(&env, &signer), &other_signature).is_err());
+    assert_eq!(factory.get_identity(&other_wallet), None);
+
+    // nor another gateway trusting the same signer
+    let other_factory = IdFactoryClient::new(&env, &env.register_contract(None, IdFactory));
+    other_factory.initialize(&Address::generate(&env), &env.deployer().upload_contract_wasm(identity_contract::WASM));
+    let other_gateway = GatewayClient::new(&env, &env.register_contract(None, Gateway));
+    other_factory.transfer_ownership(&other_gateway.address);
+    other_gateway.initialize(&Address::generate(&env), &other_factory.address, &vec![&env, signer_key(&env, &signer)]);
+    assert!(other_gateway.try_deploy_identity_with_salt(&other_wallet, &other_salt, &0, &signer_key(&env, &signer), &other_signature).is_err());
+
+    // nor another network
+    env.ledger().set_network_id([1; 32]);
+    assert!(gateway.try_deploy_identity_with_salt(&other_wallet, &other_salt, &0, &signer_key(&env, &signer), &other_signature).is_err());
+    assert_eq!(other_factory.get_identity(&other_wallet), None);
+    assert_eq!(factory.get_identity(&other_wallet), None);
+}
+
+#[test]
+fn test_deploy_identity_with_expired_signature() {
+    let env = Env::default();
+    let (gateway, factory, signer) = create_gateway(&env);
+    env.ledger().set_timestamp(1_000);
+
+    let wallet = Address::generate(&env);
+    let salt = String::from_str(&env, "alice");
+    let expired_signature = sign_deployment(&env, &gateway, &signer, &wallet, &salt, 999);
+    assert_eq!(
+        gateway.try_deploy_identity_with_salt(&wallet, &salt, &999, &signer_key(&env, &signer), &expired_signature),
+        Err(Ok(GatewayError::ExpiredSignature))
+    );
+
+    let signature = sign_deployment(&env, &gateway, &signer, &wallet, &salt, 1_000);
+    let identity = gateway.deploy_identity_with_salt(&wallet, &salt, &1_000, &signer_key(&env, &signer), &signature);
+    assert_eq!(factory.get_identity(&wallet), Some(identity));
+}
+
+#[test]
+fn test_approve_and_revoke_signer() {
+    let env = Env::default();
+    let (gateway, factory, signer) = create_gateway(&env);
+
+    let other_signer = Keypair::generate(&mut thread_rng());
+    let wallet = Address::generate(&env);
+    let salt = String::from_str(&env, "alice");
+    let signature = sign_deployment(&env, &gateway, &other_signer, &wallet, &salt, 0);
+    assert_eq!(
+        gateway.try_deploy_identity_with_salt(&wallet, &salt, &0, &signer_key(&env, &other_signer), &signature),
+        Err(Ok(GatewayError::UnapprovedSigner))
+    );
+
+    gateway.approve_signer(&signer_key(&env, &other_signer));
+    assert_eq!(
+        gateway.try_approve_signer(&signer_key(&env, &other_signer)),
+        Err(Ok(GatewayError::SignerAlreadyApproved))
+    );
+    gateway.deploy_identity_with_salt(&wallet, &salt, &0, &signer_key(&env, &other_signer), &signature);
+    assert!(factory.get_identity(&wallet).is_some());
+
+    // The approvals of a revoked signer can no longer be used
+    gateway.revoke_signer(&signer_key(&env, &signer));
+    assert!(!gateway.is_approved_signer(&signer_key(&env, &signer)));
+    let other_wallet = Address::generate(&env);
+    let other_salt = String::from_str(&env, "bob");
+    let other_signature = sign_deployment(&env, &gateway, &signer, &other_wallet, &other_salt, 0);
+    assert_eq!(
+        gateway.try_deploy_identity_with_salt(&other_wallet, &other_salt, &0, &signer_key(&env, &signer), &other_signature),
+        Err(Ok(GatewayError::UnapprovedSigner))
+    );
+    assert_eq!(
+        gateway.try_revoke_signer(&signer_key(&env, &signer)),
+        Err(Ok(GatewayError::SignerNotApproved))
+    );
+}
+
+#[test]
+fn test_revoke_signature() {
+    let env = Env::default();
+    let (gateway, factory, signer) = create_gateway(&env);
+
+    let wallet = Address::generate(&env);
+    let salt = String::from_str(&env, "alice");
+    let signature = sign_deployment(&env, &gateway, &signer, &wallet, &salt, 0);
+
+    gateway.revoke_signature(&signature);
+    assert!(gateway.is_signature_revoked(&signature));
+    assert_eq!(
+        gateway.try_revoke_signature(&signature),
+        Err(Ok(GatewayError::SignatureAlreadyRevoked))
+    );
+    assert_eq!(
+        gateway.try_deploy_identity_with_salt(&wallet, &salt, &0, &signer_key(&env, &signer), &signature),
+        Err(Ok(GatewayError::RevokedSignature))
+    );
+
+    gateway.approve_signature(&signature);
+    assert!(!gateway.is_signature_revoked(&signature));
+    assert_eq!(
+        gateway.try_approve_signature(&signature),
+        Err(Ok(GatewayError::SignatureNotRevoked))
+    );
+    gateway.deploy_identity_with_salt(&wallet, &salt, &0, &signer_key(&env, &signer), &signature);
+    assert!(factory.get_identity(&wallet).is_some());
+}
+
+#[test]
+fn test_transfer_factory_ownership() {
+    let env = Env::default();
+    let (gateway, factory, _) = create_gateway(&env);
+
+    let new_owner = Address::generate(&env);
+    let admin = gateway.get_admin();
+    gateway.transfer_factory_ownership(&new_owner);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(factory.get_owner(), new_owner);
+}
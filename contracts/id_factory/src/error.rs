@@ -0,0 +1,16 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum IdFactoryError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    SaltTaken = 3,
+    WalletAlreadyLinked = 4,
+    WalletNotLinked = 5,
+    Unauthorized = 6,
+    MaxWalletsReached = 7,
+    CannotUnlinkCaller = 8,
+    IdentityNotDeployed = 9
+}
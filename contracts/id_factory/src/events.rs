@@ -0,0 +1,33 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+
+/**
+ * Emitted when the factory deploys an identity.
+ * Topics: ("Deployed", identity). Data: the salt of the deployment.
+ */
+pub(crate) fn deployed(e: &Env, identity: &Address, salt: &String) {
+    e.events().publish((Symbol::new(e, "Deployed"), identity.clone()), salt.clone());
+}
+
+/**
+ * Emitted when a wallet is linked to an identity.
+ * Topics: ("WalletLinked", wallet, identity). Data: ().
+ */
+pub(crate) fn wallet_linked(e: &Env, wallet: &Address, identity: &Address) {
+    e.events().publish((Symbol::new(e, "WalletLinked"), wallet.clone(), identity.clone()), ());
+}
+
+/**
+ * Emitted when a wallet is unlinked from an identity.
+ * Topics: ("WalletUnlinked", wallet, identity). Data: ().
+ */
+pub(crate) fn wallet_unlinked(e: &Env, wallet: &Address, identity: &Address) {
+    e.events().publish((Symbol::new(e, "WalletUnlinked"), wallet.clone(), identity.clone()), ());
+}
+
+/**
+ * Emitted when the ownership of the factory is transferred.
+ * Topics: ("OwnershipTransferred", previous_owner, new_owner). Data: ().
+ */
+pub(crate) fn ownership_transferred(e: &Env, previous_owner: &Address, new_owner: &Address) {
+    e.events().publish((Symbol::new(e, "OwnershipTransferred"), previous_owner.clone(), new_owner.clone()), ());
+}
@@ -0,0 +1,296 @@
+use soroban_sdk::{contract, contractimpl, contracttype, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec};
+use onchain_id::{erc734::{traits::ERC734Client, MANAGEMENT_KEY}, key_store::address_to_key, storage::{extend_instance, get_persistent, set_persistent}};
+
+use crate::{error::IdFactoryError, events};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Owner,
+    IdentityWasmHash,
+    SaltTaken(BytesN<32>),
+    UserIdentity(Address),
+    Wallets(Address),
+    MaxWallets,
+    IdentityMaxWallets(Address),
+}
+
+/**
+ * Default maximum number of wallets linked to an identity, as in the Solidity factory.
+ * The owner of the factory changes the default, and sets the maximum of a given identity.
+ */
+pub const DEFAULT_MAX_WALLETS: u32 = 101;
+
+/**
+ * Factory deploying the identities of the users, mirroring the IdFactory contract of the Solidity
+ * implementation. Identities are deployed by the factory from the wasm hash of the identity contract
+ * with a salt derived from a string chosen by the owner of the factory, so their address only depends
+ * on the factory and on this string. Each salt can only be used once.
+ * The factory keeps the identity of each wallet of the users: a wallet is linked to at most one
+ * identity, and an identity to a limited number of wallets.
+ */
+#[contract]
+pub struct IdFactory;
+
+#[contractimpl]
+impl IdFactory {
+    /**
+    * Initializes the factory with its owner and the identity contract it deploys.
+    * Can only be called once, right after the deployment of the factory.
+    * @param _owner address allowed to deploy identities, which must authorize the initialization
+    * @param _identityWasmHash hash of the uploaded wasm of the identity contract
+    */
+    pub fn initialize(e: Env, owner: Address, identity_wasm_hash: BytesN<32>) -> Result<(), IdFactoryError> {
+        if e.storage().instance().has(&DataKey::Owner) {
+            return Err(IdFactoryError::AlreadyInitialized);
+        }
+
+        owner.require_auth();
+        e.storage().instance().set(&DataKey::Owner, &owner);
+        e.storage().instance().set(&DataKey::IdentityWasmHash, &identity_wasm_hash);
+        extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Deploys an identity for `wallet` and links the wallet to it.
+    * The key of the wallet, i.e. its ed25519 public key or its contract id, is registered as the
+    * MANAGEMENT key of the identity. The factory is recorded as the owner address of the identity,
+    * having initialized it.
+    * MUST only be done by the owner of the factory.
+    * @param _wallet the wallet of the user, which must not be linked to an identity yet
+    * @param _salt the salt of the deployment, which must not have been used yet
+    * @return identity Returns the address of the deployed identity
+    */
+    pub fn create_identity(e: Env, wallet: Address, salt: String) -> Result<Address, IdFactoryError> {
+        get_owner(&e)?.require_auth();
+
+        let user_identity_key = DataKey::UserIdentity(wallet.clone());
+        if e.storage().persistent().has(&user_identity_key) {
+            return Err(IdFactoryError::WalletAlreadyLinked);
+        }
+
+        let deployment_salt = deployment_salt(&e, &salt);
+        let salt_taken_key = DataKey::SaltTaken(deployment_salt.clone());
+        if e.storage().persistent().has(&salt_taken_key) {
+            return Err(IdFactoryError::SaltTaken);
+        }
+
+        let wasm_hash: BytesN<32> = e.storage().instance().get(&DataKey::IdentityWasmHash).ok_or(IdFactoryError::NotInitialized)?;
+        let identity = e.deployer().with_current_contract(deployment_salt).deploy(wasm_hash);
+        e.invoke_contract::<()>(
+            &identity,
+            &Symbol::new(&e, "initialize"),
+            vec![&e, address_to_key(&e, &wallet).into_val(&e), e.current_contract_address().into_val(&e)],
+        );
+
+        set_persistent(&e, &salt_taken_key, &true);
+        events::deployed(&e, &identity, &salt);
+        store_wallet(&e, &wallet, &identity)?;
+        Ok(identity)
+    }
+
+    /**
+    * Links `wallet` to the identity of the caller.
+    * MUST only be done by a wallet linked to the identity whose key holds a MANAGEMENT key of the
+    * identity, and MUST be authorized by the linked wallet.
+    * @param _caller wallet of the identity authorizing the call
+    * @param _wallet the wallet to link, which must authorize the call and not be linked to an identity yet
+    */
+    pub fn link_wallet(e: Env, caller: Address, wallet: Address) -> Result<(), IdFactoryError> {
+        let identity = caller_identity(&e, &caller)?;
+        require_management_key(&e, &caller, &identity)?;
+
+        if e.storage().persistent().has(&DataKey::UserIdentity(wallet.clone())) {
+            return Err(IdFactoryError::WalletAlreadyLinked);
+        }
+        wallet.require_auth();
+        store_wallet(&e, &wallet, &identity)
+    }
+
+    /**
+    * Unlinks `wallet` from its identity.
+    * MUST only be done by another wallet linked to the identity whose key holds a MANAGEMENT key of
+    * the identity.
+    * @param _caller wallet of the identity authorizing the call
+    * @param _wallet the wallet to unlink
+    */
+    pub fn unlink_wallet(e: Env, caller: Address, wallet: Address) -> Result<(), IdFactoryError> {
+        if caller == wallet {
+            return Err(IdFactoryError::CannotUnlinkCaller);
+        }
+
+        let user_identity_key = DataKey::UserIdentity(wallet.clone());
+        let identity: Address = get_persistent(&e, &user_identity_key).ok_or(IdFactoryError::WalletNotLinked)?;
+        if caller_identity(&e, &caller)? != identity {
+            return Err(IdFactoryError::Unauthorized);
+        }
+        require_management_key(&e, &caller, &identity)?;
+
+        let wallets_key = DataKey::Wallets(identity.clone());
+        let mut wallets: Vec<Address> = get_persistent(&e, &wallets_key).unwrap_or(vec![&e]);
+        if let Some(wallet_index) = wallets.first_index_of(&wallet) {
+            wallets.remove(wallet_index);
+        }
+        set_persistent(&e, &wallets_key, &wallets);
+        e.storage().persistent().remove(&user_identity_key);
+        events::wallet_unlinked(&e, &wallet, &identity);
+        Ok(())
+    }
+
+    /**
+    * Sets the default maximum number of wallets linked to an identity, applying to the identities
+    * without a maximum of their own. Identities already linked to more wallets keep them, but cannot
+    * link new ones.
+    * MUST only be done by the owner of the factory.
+    */
+    pub fn set_max_wallets(e: Env, max_wallets: u32) -> Result<(), IdFactoryError> {
+        get_owner(&e)?.require_auth();
+
+        e.storage().instance().set(&DataKey::MaxWallets, &max_wallets);
+        extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Returns the default maximum number of wallets linked to an identity.
+    */
+    pub fn get_max_wallets(e: Env) -> u32 {
+        get_max_wallets(&e)
+    }
+
+    /**
+    * Sets the maximum number of wallets linked to `identity`, overriding the default maximum. An
+    * identity already linked to more wallets keeps them, but cannot link new ones.
+    * MUST only be done by the owner of the factory.
+    * @param _identity an identity deployed by the factory
+    * @param _maxWallets the maximum number of wallets linked to the identity
+    */
+    pub fn set_identity_max_wallets(e: Env, identity: Address, max_wallets: u32) -> Result<(), IdFactoryError> {
+        get_owner(&e)?.require_auth();
+
+        if !e.storage().persistent().has(&DataKey::Wallets(identity.clone())) {
+            return Err(IdFactoryError::IdentityNotDeployed);
+        }
+        set_persistent(&e, &DataKey::IdentityMaxWallets(identity), &max_wallets);
+        Ok(())
+    }
+
+    /**
+    * Returns the maximum number of wallets linked to `identity`: its own maximum if set, or the
+    * default one.
+    */
+    pub fn get_identity_max_wallets(e: Env, identity: Address) -> u32 {
+        get_identity_max_wallets(&e, &identity)
+    }
+
+    /**
+    * Returns the identity linked to `wallet`, if any.
+    */
+    pub fn get_identity(e: Env, wallet: Address) -> Option<Address> {
+        get_persistent(&e, &DataKey::UserIdentity(wallet))
+    }
+
+    /**
+    * Returns the wallets linked to `identity`.
+    */
+    pub fn get_wallets(e: Env, identity: Address) -> Vec<Address> {
+        get_persistent(&e, &DataKey::Wallets(identity)).unwrap_or(vec![&e])
+    }
+
+    /**
+    * Returns TRUE if `salt` was already used to deploy an identity.
+    */
+    pub fn is_salt_taken(e: Env, salt: String) -> bool {
+        e.storage().persistent().has(&DataKey::SaltTaken(deployment_salt(&e, &salt)))
+    }
+
+    /**
+    * Returns the address of the identity deployed, or to be deployed, with `salt`.
+    */
+    pub fn get_identity_address(e: Env, salt: String) -> Address {
+        e.deployer().with_current_contract(deployment_salt(&e, &salt)).deployed_address()
+    }
+
+    /**
+    * Transfers the ownership of the factory, e.g. to a gateway deploying identities on behalf of the owner.
+    * MUST only be done by the owner of the factory.
+    * @param _newOwner the new owner, an account or a contract
+    */
+    pub fn transfer_ownership(e: Env, new_owner: Address) -> Result<(), IdFactoryError> {
+        let owner = get_owner(&e)?;
+        owner.require_auth();
+
+        e.storage().instance().set(&DataKey::Owner, &new_owner);
+        extend_instance(&e);
+        events::ownership_transferred(&e, &owner, &new_owner);
+        Ok(())
+    }
+
+    /**
+    * Returns the owner of the factory.
+    */
+    pub fn get_owner(e: Env) -> Result<Address, IdFactoryError> {
+        get_owner(&e)
+    }
+}
+
+fn get_owner(e: &Env) -> Result<Address, IdFactoryError> {
+    e.storage().instance().get(&DataKey::Owner).ok_or(IdFactoryError::NotInitialized)
+}
+
+fn get_max_wallets(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::MaxWallets).unwrap_or(DEFAULT_MAX_WALLETS)
+}
+
+fn get_identity_max_wallets(e: &Env, identity: &Address) -> u32 {
+    get_persistent(e, &DataKey::IdentityMaxWallets(identity.clone())).unwrap_or_else(|| get_max_wallets(e))
+}
+
+/**
+ * Links `wallet` to `identity`, if the identity has not reached the maximum number of wallets.
+ */
+fn store_wallet(e: &Env, wallet: &Address, identity: &Address) -> Result<(), IdFactoryError> {
+    let wallets_key = DataKey::Wallets(identity.clone());
+    let mut wallets: Vec<Address> = get_persistent(e, &wallets_key).unwrap_or(vec![e]);
+    if wallets.len() >= get_identity_max_wallets(e, identity) {
+        return Err(IdFactoryError::MaxWalletsReached);
+    }
+
+    wallets.push_back(wallet.clone());
+    set_persistent(e, &wallets_key, &wallets);
+    set_persistent(e, &DataKey::UserIdentity(wallet.clone()), identity);
+    events::wallet_linked(e, wallet, identity);
+    Ok(())
+}
+
+/**
+ * Returns the identity the wallet `caller` is linked to.
+ */
+fn caller_identity(e: &Env, caller: &Address) -> Result<Address, IdFactoryError> {
+    get_persistent(e, &DataKey::UserIdentity(caller.clone())).ok_or(IdFactoryError::WalletNotLinked)
+}
+
+/**
+ * Requires the authorization of `caller` and checks that its key holds a MANAGEMENT key of `identity`.
+ * The identity itself is not accepted as a caller, as it also authorizes calls to other contracts
+ * for its ACTION keys.
+ */
+fn require_management_key(e: &Env, caller: &Address, identity: &Address) -> Result<(), IdFactoryError> {
+    caller.require_auth();
+    match ERC734Client::new(e, identity).try_key_has_purpose(&address_to_key(e, caller), &MANAGEMENT_KEY) {
+        Ok(Ok(true)) => Ok(()),
+        _ => Err(IdFactoryError::Unauthorized),
+    }
+}
+
+/**
+ * Returns the salt the identities are deployed with: keccak256 of "OID" followed by the XDR encoding of
+ * `salt`. The Solidity factory hashes "OID" followed by the raw bytes of the string instead, so the
+ * deployment salts, and the identity addresses, differ from the ones of the Solidity factory.
+ */
+fn deployment_salt(e: &Env, salt: &String) -> BytesN<32> {
+    let mut salt_bytes = Bytes::from_slice(e, b"OID");
+    salt_bytes.append(&salt.clone().to_xdr(e));
+    e.crypto().keccak256(&salt_bytes).to_bytes()
+}
@@ -0,0 +1,296 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{error::IdFactoryError, id_factory::{IdFactory, IdFactoryClient, DEFAULT_MAX_WALLETS}};
+use onchain_id::{erc734::{ED25519_KEY_TYPE, MANAGEMENT_KEY}, key_store::address_to_key};
+use soroban_sdk::{testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthInvoke}, vec, Address, BytesN, Env, IntoVal, String, Symbol};
+
+// The identity contract deployed by the factory, built with `stellar contract build` before running the tests.
+mod identity_contract {
+    use soroban_sdk::auth::Context;
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/identity.wasm");
+}
+
+// Registers a factory initialized with the uploaded wasm of the identity contract.
+fn create_factory(env: &Env) -> (IdFactoryClient<'_>, Address) {
+    env.mock_all_auths();
+    // Each identity call instantiates the wasm of the identity
+    env.budget().reset_unlimited();
+    let contract_id = env.register_contract(None, IdFactory);
+    let client = IdFactoryClient::new(env, &contract_id);
+
+    let owner = Address::generate(env);
+    let wasm_hash = env.deployer().upload_contract_wasm(identity_contract::WASM);
+    client.initialize(&owner, &wasm_hash);
+    (client, owner)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let (client, owner) = create_factory(&env);
+
+    assert_eq!(client.get_owner(), owner);
+    assert_eq!(
+        client.try_initialize(&owner, &BytesN::from_array(&env, &[0; 32])),
+        Err(Ok(IdFactoryError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_create_identity() {
+    let env = Env::default();
+    let (client, owner) = create_factory(&env);
+
+    let wallet = Address::generate(&env);
+    let salt = String::from_str(&env, "alice");
+    assert!(!client.is_salt_taken(&salt));
+    let expected_identity = client.get_identity_address(&salt);
+
+    let identity = client.create_identity(&wallet, &salt);
+    assert_eq!(
+        env.auths()[0],
+        (
+            owner,
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "create_identity"),
+                    (wallet.clone(), salt.clone()).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )
+    );
+    assert_eq!(identity, expected_identity);
+    assert_eq!(client.get_identity(&wallet), Some(identity.clone()));
+    assert!(client.is_salt_taken(&salt));
+
+    // The wallet holds the MANAGEMENT key of the deployed identity
+    let identity_client = identity_contract::Client::new(&env, &identity);
+    let wallet_key = address_to_key(&env, &wallet);
+    assert!(identity_client.key_has_purpose(&wallet_key, &MANAGEMENT_KEY));
+    assert_eq!(identity_client.get_keys_by_purpose(&MANAGEMENT_KEY).len(), 1);
+    assert_eq!(client.get_identity(&Address::generate(&env)), None);
+}
+
+#[test]
+fn test_create_identity_salt_taken() {
+    let env = Env::default();
+    let (client, _) = create_factory(&env);
+
+    let salt = String::from_str(&env, "alice");
+    client.create_identity(&Address::generate(&env), &salt);
+    assert_eq!(
+        client.try_create_identity(&Address::generate(&env), &salt),
+        Err(Ok(IdFactoryError::SaltTaken))
+    );
+
+    // Another salt deploys another identity
+    let other_salt = String::from_str(&env, "bob");
+    let other_identity = client.create_identity(&Address::generate(&env), &other_salt);
+    assert_eq!(other_identity, client.get_identity_address(&other_salt));
+}
+
+#[test]
+fn test_create_identity_wallet_already_linked() {
+    let env = Env::default();
+    let (client, _) = create_factory(&env);
+
+    let wallet = Address::generate(&env);
+    let identity = client.create_identity(&wallet, &String::from_str(&env, "alice"));
+    assert_eq!(
+        client.try_create_identity(&wallet, &String::from_str(&env, "alice2")),
+        Err(Ok(IdFactoryError::WalletAlreadyLinked))
+    );
+    assert!(!client.is_salt_taken(&String::from_str(&env, "alice2")));
+    assert_eq!(client.get_identity(&wallet), Some(identity));
+}
+
+#[test]
+fn test_link_wallet() {
+    let env = Env::default();
+    let (client, _) = create_factory(&env);
+
+    let wallet = Address::generate(&env);
+    let identity = client.create_identity(&wallet, &String::from_str(&env, "alice"));
+    assert_eq!(client.get_wallets(&identity), vec![&env, wallet.clone()]);
+
+    // A wallet holding a MANAGEMENT key of the identity links another wallet, which authorizes it
+    let second_wallet = Address::generate(&env);
+    client.link_wallet(&wallet, &second_wallet);
+    let invocation = AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            client.address.clone(),
+            Symbol::new(&env, "link_wallet"),
+            (wallet.clone(), second_wallet.clone()).into_val(&env),
+        )),
+        sub_invocations: std::vec![],
+    };
+    assert_eq!(env.auths(), std::vec![(wallet.clone(), invocation.clone()), (second_wallet.clone(), invocation)]);
+    assert_eq!(client.get_identity(&second_wallet), Some(identity.clone()));
+
+    // A wallet cannot be linked without its authorization
+    let unauthorized_wallet = Address::generate(&env);
+    assert!(client
+        .mock_auths(&[MockAuth {
+            address: &wallet,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "link_wallet",
+                args: (wallet.clone(), unauthorized_wallet.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_link_wallet(&wallet, &unauthorized_wallet)
+        .is_err());
+    assert_eq!(client.get_identity(&unauthorized_wallet), None);
+    env.mock_all_auths();
+
+    // The identity itself does not link wallets, as it also authorizes calls for its ACTION keys
+    let third_wallet = Address::generate(&env);
+    assert_eq!(client.try_link_wallet(&identity, &third_wallet), Err(Ok(IdFactoryError::WalletNotLinked)));
+    client.link_wallet(&wallet, &third_wallet);
+    assert_eq!(client.get_wallets(&identity), vec![&env, wallet.clone(), second_wallet.clone(), third_wallet.clone()]);
+
+    // A linked wallet without a MANAGEMENT key of the identity cannot link wallets
+    assert_eq!(
+        client.try_link_wallet(&second_wallet, &Address::generate(&env)),
+        Err(Ok(IdFactoryError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_link_wallet(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(IdFactoryError::WalletNotLinked))
+    );
+
+    // Wallets are unique across identities
+    let other_wallet = Address::generate(&env);
+    client.create_identity(&other_wallet, &String::from_str(&env, "bob"));
+    assert_eq!(
+        client.try_link_wallet(&other_wallet, &second_wallet),
+        Err(Ok(IdFactoryError::WalletAlreadyLinked))
+    );
+    assert_eq!(
+        client.try_link_wallet(&wallet, &wallet),
+        Err(Ok(IdFactoryError::WalletAlreadyLinked))
+    );
+}
+
+#[test]
+fn test_max_wallets() {
+    let env = Env::default();
+    let (client, _) = create_factory(&env);
+    assert_eq!(client.get_max_wallets(), DEFAULT_MAX_WALLETS);
+
+    client.set_max_wallets(&2);
+    assert_eq!(client.get_max_wallets(), 2);
+
+    let wallet = Address::generate(&env);
+    let identity = client.create_identity(&wallet, &String::from_str(&env, "alice"));
+    let second_wallet = Address::generate(&env);
+    client.link_wallet(&wallet, &second_wallet);
+    assert_eq!(
+        client.try_link_wallet(&wallet, &Address::generate(&env)),
+        Err(Ok(IdFactoryError::MaxWalletsReached))
+    );
+
+    // Unlinking a wallet frees a slot
+    client.unlink_wallet(&wallet, &second_wallet);
+    client.link_wallet(&wallet, &Address::generate(&env));
+    assert_eq!(client.get_wallets(&identity).len(), 2);
+
+    // The owner sets the maximum of a given identity
+    client.set_identity_max_wallets(&identity, &3);
+    assert_eq!(client.get_identity_max_wallets(&identity), 3);
+    client.link_wallet(&wallet, &Address::generate(&env));
+    assert_eq!(
+        client.try_link_wallet(&wallet, &Address::generate(&env)),
+        Err(Ok(IdFactoryError::MaxWalletsReached))
+    );
+    let other_identity = client.create_identity(&Address::generate(&env), &String::from_str(&env, "bob"));
+    assert_eq!(client.get_identity_max_wallets(&other_identity), 2);
+    assert_eq!(
+        client.try_set_identity_max_wallets(&Address::generate(&env), &3),
+        Err(Ok(IdFactoryError::IdentityNotDeployed))
+    );
+}
+
+#[test]
+fn test_unlink_wallet() {
+    let env = Env::default();
+    let (client, _) = create_factory(&env);
+
+    let wallet = Address::generate(&env);
+    let identity = client.create_identity(&wallet, &String::from_str(&env, "alice"));
+    let second_wallet = Address::generate(&env);
+    let third_wallet = Address::generate(&env);
+    client.link_wallet(&wallet, &second_wallet);
+    client.link_wallet(&wallet, &third_wallet);
+
+    assert_eq!(
+        client.try_unlink_wallet(&wallet, &wallet),
+        Err(Ok(IdFactoryError::CannotUnlinkCaller))
+    );
+    // Only a wallet holding a MANAGEMENT key of the same identity unlinks wallets
+    assert_eq!(
+        client.try_unlink_wallet(&second_wallet, &third_wallet),
+        Err(Ok(IdFactoryError::Unauthorized))
+    );
+    let other_wallet = Address::generate(&env);
+    client.create_identity(&other_wallet, &String::from_str(&env, "bob"));
+    assert_eq!(
+        client.try_unlink_wallet(&other_wallet, &second_wallet),
+        Err(Ok(IdFactoryError::Unauthorized))
+    );
+
+    client.unlink_wallet(&wallet, &second_wallet);
+    assert_eq!(client.get_identity(&second_wallet), None);
+    assert_eq!(client.get_wallets(&identity), vec![&env, wallet.clone(), third_wallet.clone()]);
+    assert_eq!(
+        client.try_unlink_wallet(&wallet, &second_wallet),
+        Err(Ok(IdFactoryError::WalletNotLinked))
+    );
+
+    // Neither the identity itself nor a wallet holding an ACTION key of the identity unlinks wallets
+    assert_eq!(client.try_unlink_wallet(&identity, &wallet), Err(Ok(IdFactoryError::WalletNotLinked)));
+    identity_contract::Client::new(&env, &identity).add_key(&wallet, &address_to_key(&env, &third_wallet), &2, &ED25519_KEY_TYPE);
+    assert_eq!(
+        client.try_unlink_wallet(&third_wallet, &wallet),
+        Err(Ok(IdFactoryError::Unauthorized))
+    );
+
+    // A wallet holding a MANAGEMENT key unlinks any other wallet, which can then be linked again
+    identity_contract::Client::new(&env, &identity).add_key(&wallet, &address_to_key(&env, &third_wallet), &MANAGEMENT_KEY, &ED25519_KEY_TYPE);
+    client.unlink_wallet(&third_wallet, &wallet);
+    assert_eq!(client.get_wallets(&identity), vec![&env, third_wallet]);
+    client.link_wallet(&other_wallet, &wallet);
+    assert_eq!(client.get_identity(&wallet), Some(client.get_identity(&other_wallet).unwrap()));
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let env = Env::default();
+    let (client, owner) = create_factory(&env);
+
+    let new_owner = Address::generate(&env);
+    client.transfer_ownership(&new_owner);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            owner,
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "transfer_ownership"),
+                    (new_owner.clone(),).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(client.get_owner(), new_owner);
+
+    // The new owner deploys the identities
+    client.create_identity(&Address::generate(&env), &String::from_str(&env, "alice"));
+    assert_eq!(env.auths()[0].0, new_owner);
+}
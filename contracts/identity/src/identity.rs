@@ -0,0 +1,739 @@
+use soroban_sdk::{auth::{Context, CustomAccountInterface}, contract, contractimpl, crypto::Hash, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, Error, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use onchain_id::{claims_issuer::{self, traits::ClaimIssuerClient}, events, erc734::{traits::IERC734, ACTION_KEY, CLAIM_SIGNER_KEY, MANAGEMENT_KEY, SECP256R1_KEY_TYPE}, erc735::traits::IERC735, error::OnChainIdError, implementation_authority::ImplementationAuthorityClient, key_store::{self, address_to_key, require_key_purpose, store_key}, signature, storage, structs::{Claim, DataKey, Execution, Key, KeySignature, TtlConfig}};
+
+#[contract]
+pub struct Identity;
+
+/**
+ * Version of the storage layout of the identity, recorded at initialization and by migrate.
+ * Identities deployed before the version was recorded are at version 0.
+ */
+pub const CONTRACT_VERSION: u32 = 1;
+
+/**
+ * Returns the version of the storage layout of the identity.
+ */
+fn get_version(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+/**
+ * Returns the version of the implementation the identity last synced to with its implementation authority.
+ */
+fn get_implementation_version(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::ImplementationVersion).unwrap_or(0)
+}
+
+/**
+ * Replaces the wasm of the identity, once its storage was migrated to the layout of the current version.
+ */
+fn update_wasm(e: &Env, wasm_hash: &BytesN<32>) -> Result<(), OnChainIdError> {
+    if get_version(e) < CONTRACT_VERSION {
+        return Err(OnChainIdError::MigrationRequired);
+    }
+
+    e.deployer().update_current_contract_wasm(wasm_hash.clone());
+    Ok(())
+}
+
+/**
+ * Returns the id of the claim of `issuer` on `topic`, i.e. keccak256(xdr(issuer address) || xdr(topic)).
+ */
+fn derive_claim_id(e: &Env, issuer: &Address, topic: u128) -> BytesN<32> {
+    let mut claim_id_bytes = Bytes::new(e);
+    claim_id_bytes.append(&issuer.clone().to_xdr(e));
+    claim_id_bytes.append(&topic.to_xdr(e));
+    e.crypto().keccak256(&claim_id_bytes).to_bytes()
+}
+
+/**
+ * Checks a claim with {IClaimIssuer-isClaimValid} of the issuer contract. Claims issued by the
+ * identity itself are not checked, as in the Solidity implementation.
+ */
+fn validate_claim(e: &Env, topic: u128, issuer: &Address, signature: &Bytes, data: &Bytes, valid_from: u64, valid_until: u64) -> Result<(), OnChainIdError> {
+    let identity = e.current_contract_address();
+    if *issuer == identity {
+        return Ok(());
+    }
+
+    let issuer_client = ClaimIssuerClient::new(e, issuer);
+    match issuer_client.try_is_claim_valid(&identity, &topic, signature, data, &valid_from, &valid_until) {
+        Ok(Ok(true)) => Ok(()),
+        Err(Ok(error)) => Err(error),
+        _ => Err(OnChainIdError::InvalidClaim),
+    }
+}
+
+/**
+ * Validates the claim with its issuer and stores it, replacing any claim with the same issuer and topic.
+ * The issuer only validates a claim within its validity window, so an expired claim is rejected with
+ * ClaimExpired and a claim which is not valid yet with ClaimNotYetValid, a claim never being stored
+ * without its signature being checked.
+ */
+fn store_claim(e: &Env, topic: u128, scheme: u128, issuer: Address, signature: Bytes, data: Bytes, uri: String, valid_from: u64, valid_until: u64) -> Result<BytesN<32>, OnChainIdError> {
+    let now = e.ledger().timestamp();
+    if valid_until != 0 && valid_until < now {
+        return Err(OnChainIdError::ClaimExpired);
+    }
+    if valid_from > now {
+        return Err(OnChainIdError::ClaimNotYetValid);
+    }
+    validate_claim(e, topic, &issuer, &signature, &data, valid_from, valid_until)?;
+
+    let claim_id = derive_claim_id(e, &issuer, topic);
+    let map_key = DataKey::Claim(claim_id.clone());
+    if let Some(mut retrieved_claim) = storage::get_persistent::<Claim>(e, &map_key) {
+        retrieved_claim.topic = topic;
+        retrieved_claim.scheme = scheme;
+        retrieved_claim.signature = signature;
+        retrieved_claim.data = data;
+        retrieved_claim.uri = uri;
+        retrieved_claim.valid_from = valid_from;
+        retrieved_claim.valid_until = valid_until;
+
+        storage::set_persistent(e, &map_key, &retrieved_claim);
+        events::claim_changed(e, &claim_id, &retrieved_claim);
+    } else {
+        let claim: Claim = Claim {
+            topic,
+            scheme,
+            issuer,
+            signature,
+            data,
+            uri,
+            valid_from,
+            valid_until
+        };
+        insert_claim(e, &claim_id, &claim);
+    }
+
+    Ok(claim_id)
+}
+
+/**
+ * Stores a new claim and adds its id to the claim ids of its topic.
+ */
+fn insert_claim(e: &Env, claim_id: &BytesN<32>, claim: &Claim) {
+    storage::set_persistent(e, &DataKey::Claim(claim_id.clone()), claim);
+    events::claim_added(e, claim_id, claim);
+
+    let claim_topic_key = DataKey::ClaimTopic(claim.topic);
+    if let Some(mut retrieved_claim_topics) = storage::get_persistent::<Vec<BytesN<32>>>(e, &claim_topic_key) {
+        retrieved_claim_topics.push_back(claim_id.clone());
+        storage::set_persistent(e, &claim_topic_key, &retrieved_claim_topics);
+    } else {
+        let retrieved_claim_topics = vec![e, claim_id.clone()];
+        storage::set_persistent(e, &claim_topic_key, &retrieved_claim_topics);
+        storage::add_to_index(e, &DataKey::ClaimTopics, claim.topic);
+    }
+}
+
+/**
+ * Removes a stored claim and its id from the claim ids of its topic.
+ */
+fn remove_stored_claim(e: &Env, claim_id: &BytesN<32>, claim: &Claim) {
+    e.storage().persistent().remove(&DataKey::Claim(claim_id.clone()));
+
+    let topic_key = DataKey::ClaimTopic(claim.topic);
+    if let Some(mut retrieved_claim_topic) = storage::get_persistent::<Vec<BytesN<32>>>(e, &topic_key) {
+        if let Some(claim_index) = retrieved_claim_topic.first_index_of(claim_id) {
+            retrieved_claim_topic.remove(claim_index);
+            storage::set_persistent(e, &topic_key, &retrieved_claim_topic);
+        }
+    }
+
+    events::claim_removed(e, claim_id, claim);
+}
+
+/**
+ * Processes an approved execution. Executions to the identity itself are dispatched internally,
+ * as a contract cannot invoke itself, other executions invoke the target contract.
+ * @return success Returns TRUE if the call succeeded
+ */
+fn run_execution(e: &Env, execution_id: u64, execution: &Execution) -> bool {
+    let success = if execution.to == e.current_contract_address() {
+        execute_on_identity(e, &execution.fn_name, &execution.args).is_ok()
+    } else {
+        matches!(e.try_invoke_contract::<Val, Error>(&execution.to, &execution.fn_name, execution.args.clone()), Ok(Ok(_)))
+    };
+
+    if success {
+        events::executed(e, execution_id, execution);
+    } else {
+        events::execution_failed(e, execution_id, execution);
+    }
+    success
+}
+
+/**
+ * Dispatches an execution to the key and claim management of the identity. The arguments are the
+ * ones of the matching entrypoint without the `caller`, the approval process having authorized the call.
+ */
+fn execute_on_identity(e: &Env, fn_name: &Symbol, args: &Vec<Val>) -> Result<(), OnChainIdError> {
+    if *fn_name == Symbol::new(e, "add_key") {
+        let (key, purpose, key_type) = decode_execution_args(e, args)?;
+        store_key(e, key, purpose, key_type, None)
+    } else if *fn_name == Symbol::new(e, "add_secp256r1_key") {
+        let (public_key, purpose) = decode_execution_args(e, args)?;
+        store_key(e, signature::secp256r1_key_hash(e, &public_key), purpose, SECP256R1_KEY_TYPE, Some(public_key))
+    } else if *fn_name == Symbol::new(e, "remove_key") {
+        let (key, purpose) = decode_execution_args(e, args)?;
+        key_store::remove_key(e, key, purpose)
+    } else if *fn_name == Symbol::new(e, "add_claim") {
+        let (topic, scheme, issuer, signature, data, uri, valid_from, valid_until) = decode_execution_args(e, args)?;
+        store_claim(e, topic, scheme, issuer, signature, data, uri, valid_from, valid_until).map(|_| ())
+    } else if *fn_name == Symbol::new(e, "remove_claim") {
+        let (claim_id,): (BytesN<32>,) = decode_execution_args(e, args)?;
+        if let Some(retrieved_claim) = storage::get_persistent::<Claim>(e, &DataKey::Claim(claim_id.clone())) {
+            remove_stored_claim(e, &claim_id, &retrieved_claim);
+            Ok(())
+        } else {
+            Err(OnChainIdError::NoClaimFound)
+        }
+    } else {
+        Err(OnChainIdError::UnsupportedExecution)
+    }
+}
+
+fn decode_execution_args<T: TryFromVal<Env, Val>>(e: &Env, args: &Vec<Val>) -> Result<T, OnChainIdError> {
+    T::try_from_val(e, &args.into_val(e)).map_err(|_| OnChainIdError::UnsupportedExecution)
+}
+
+#[contractimpl]
+impl Identity {
+    /**
+    * Initializes the identity by registering the first MANAGEMENT key.
+    * Can only be called once, right after the deployment of the identity: identities should be
+    * deployed through the IdFactory, which initializes them in the same invocation, or deployed and
+    * initialized in the same transaction. The key of an account owner must be its own public key.
+    * @param _ownerKey ed25519 public key of the owner, registered with purpose 1 = MANAGEMENT
+    * @param _ownerAddress address of the owner, which must authorize the initialization
+    */
+    pub fn initialize(e: Env, owner_key: BytesN<32>, owner_address: Address) -> Result<(), OnChainIdError> {
+        key_store::initialize(&e, owner_key, &owner_address)?;
+        e.storage().instance().set(&DataKey::Version, &CONTRACT_VERSION);
+        Ok(())
+    }
+
+    /**
+    * Sets the TTL configuration applied when the entries of the identity are read, written or extended.
+    * MUST only be done by keys of purpose 1, or the identity itself.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the identity itself
+    * @param _threshold entries are extended when their TTL falls below this number of ledgers
+    * @param _extendTo number of ledgers the entries are extended to, at most the maximum TTL of the network
+    */
+    pub fn set_ttl_config(e: Env, caller: Address, threshold: u32, extend_to: u32) -> Result<(), OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        if threshold > extend_to || extend_to > e.storage().max_ttl() {
+            return Err(OnChainIdError::InvalidTtlConfig);
+        }
+
+        storage::set_ttl_config(&e, &TtlConfig { threshold, extend_to });
+        storage::extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Adds a secp256r1 (P-256) key to the identity, e.g. the key of a passkey.
+    * The key is registered under keccak256 of the public key, along with the full public key.
+    * MUST only be done by keys of purpose 1, or the identity itself.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the identity itself
+    * @param _publicKey the uncompressed SEC-1 encoded public key
+    * @param _purpose a uint256 specifying the key type, like 1 = MANAGEMENT, 2 = ACTION, 3 = CLAIM, 4 = ENCRYPTION
+    * @return key Returns the key under which the public key is registered
+    */
+    pub fn add_secp256r1_key(e: Env, caller: Address, public_key: BytesN<65>, purpose: u32) -> Result<BytesN<32>, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        let key = signature::secp256r1_key_hash(&e, &public_key);
+        store_key(&e, key.clone(), purpose, SECP256R1_KEY_TYPE, Some(public_key))?;
+        Ok(key)
+    }
+
+    /**
+    * Returns the full public key of a key, only stored for SECP256R1 keys.
+    * @param _key the key
+    * @return publicKey Returns the SEC-1 encoded public key
+    */
+    pub fn get_public_key(e: Env, key: BytesN<32>) -> Result<BytesN<65>, OnChainIdError> {
+        let retrieved_key = storage::get_persistent::<Key>(&e, &DataKey::Key(key)).ok_or(OnChainIdError::KeyNotRegistered)?;
+        retrieved_key.public_key.and_then(|public_key| public_key.try_into().ok()).ok_or(OnChainIdError::PublicKeyRequired)
+    }
+
+    /**
+    * Returns the message a claim issuer signs to issue a claim on this identity: the 32 bytes
+    * contract id of the identity, the topic as 16 big-endian bytes, the validity window as two
+    * 8 bytes big-endian timestamps, then the claim data.
+    * @param _topic the claim topic
+    * @param _data the claim data
+    * @param _validFrom ledger timestamp from which the claim is valid, 0 if valid right away
+    * @param _validUntil ledger timestamp until which the claim is valid, 0 if it does not expire
+    * @return message Returns the message to sign with the issuer key
+    */
+    pub fn claim_message(e: Env, topic: u128, data: Bytes, valid_from: u64, valid_until: u64) -> Bytes {
+        claims_issuer::claim_message(&e, &e.current_contract_address(), topic, &data, valid_from, valid_until)
+    }
+
+    /**
+    * Returns the validity window of a claim.
+    * @param _claimId The identity of the claim i.e. keccak256(_issuer, _topic)
+    * @return validFrom Returns the ledger timestamp from which the claim is valid, 0 if not bounded
+    * @return validUntil Returns the ledger timestamp until which the claim is valid, 0 if not bounded
+    */
+    pub fn get_claim_validity(e: Env, claim_id: BytesN<32>) -> Result<(u64, u64), OnChainIdError> {
+        let retrieved_claim = storage::get_persistent::<Claim>(&e, &DataKey::Claim(claim_id)).ok_or(OnChainIdError::NoClaimFound)?;
+        Ok((retrieved_claim.valid_from, retrieved_claim.valid_until))
+    }
+
+    /**
+    * Returns the ids of the claims of a topic which are valid at the current ledger timestamp,
+    * leaving out the expired claims and the ones which are not valid yet.
+    * @param _topic the claim topic
+    * @return claimIds Returns an array of claim IDs
+    */
+    pub fn get_valid_claims_by_topic(e: Env, topic: u128) -> Vec<BytesN<32>> {
+        let mut valid_claim_ids = Vec::new(&e);
+        let claim_ids = storage::get_persistent::<Vec<BytesN<32>>>(&e, &DataKey::ClaimTopic(topic)).unwrap_or(Vec::new(&e));
+        for claim_id in claim_ids {
+            if let Some(claim) = storage::get_persistent::<Claim>(&e, &DataKey::Claim(claim_id.clone())) {
+                if claims_issuer::is_within_validity(&e, claim.valid_from, claim.valid_until) {
+                    valid_claim_ids.push_back(claim_id);
+                }
+            }
+        }
+        valid_claim_ids
+    }
+
+    /**
+    * Upgrades the identity to a new wasm, keeping its address and its storage. The new wasm takes
+    * effect once the invocation completes, then migrate must be called to convert the storage to
+    * the layout of the new contract version.
+    * MUST only be done by keys of purpose 1, or the identity itself, once the storage of the identity
+    * was migrated to the layout of the current contract version.
+    * Identities deployed before upgrade existed, at version 0, cannot be upgraded: they must be
+    * redeployed, e.g. with the IdFactory, and their keys and claims added to the new identity.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the identity itself
+    * @param _newWasmHash hash of the uploaded wasm of the new identity contract
+    */
+    pub fn upgrade(e: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        update_wasm(&e, &new_wasm_hash)?;
+        events::upgraded(&e, &new_wasm_hash);
+        Ok(())
+    }
+
+    /**
+    * Sets the implementation authority the identity follows with sync_implementation.
+    * MUST only be done by keys of purpose 1, or the identity itself.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the identity itself
+    * @param _authority the implementation authority contract
+    */
+    pub fn set_implementation_authority(e: Env, caller: Address, authority: Address) -> Result<(), OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        e.storage().instance().set(&DataKey::ImplementationAuthority, &authority);
+        storage::extend_instance(&e);
+        events::implementation_authority_set(&e, &authority);
+        Ok(())
+    }
+
+    /**
+    * Returns the implementation authority the identity follows, if any.
+    */
+    pub fn get_implementation_authority(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::ImplementationAuthority)
+    }
+
+    /**
+    * Upgrades the identity to the implementation approved by its implementation authority, if its
+    * version is more recent than both the one the identity last synced to and the contract version
+    * of its storage, so that an identity upgraded with upgrade is never downgraded to older code.
+    * The new wasm takes effect once the invocation completes, then migrate must be called as after upgrade.
+    * Anyone can call it, e.g. to roll an approved implementation to many identities at once.
+    * @return upgraded Returns TRUE if the identity was upgraded, FALSE if it already runs the approved version or a more recent one
+    */
+    pub fn sync_implementation(e: Env) -> Result<bool, OnChainIdError> {
+        let authority: Address = e.storage().instance().get(&DataKey::ImplementationAuthority).ok_or(OnChainIdError::ImplementationAuthorityNotSet)?;
+        let implementation = ImplementationAuthorityClient::new(&e, &authority).get_implementation();
+        if implementation.version <= get_implementation_version(&e).max(get_version(&e)) {
+            return Ok(false);
+        }
+
+        update_wasm(&e, &implementation.wasm_hash)?;
+        e.storage().instance().set(&DataKey::ImplementationVersion, &implementation.version);
+        events::implementation_synced(&e, implementation.version, &implementation.wasm_hash);
+        Ok(true)
+    }
+
+    /**
+    * Returns the version of the implementation the identity last synced to, 0 if it never synced.
+    */
+    pub fn get_implementation_version(e: Env) -> u32 {
+        get_implementation_version(&e)
+    }
+
+    /**
+    * Converts the storage of the identity from the layout of its recorded version to the layout of
+    * the current contract version, then records the current version. Does nothing when the storage
+    * is already at the current version. Version 1 being the first version which can be upgraded, no
+    * layout is converted yet.
+    * MUST only be done by keys of purpose 1, or the identity itself.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the identity itself
+    * @return version Returns the current contract version
+    */
+    pub fn migrate(e: Env, caller: Address) -> Result<u32, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        let version = get_version(&e);
+        if version >= CONTRACT_VERSION {
+            return Ok(version);
+        }
+
+        e.storage().instance().set(&DataKey::Version, &CONTRACT_VERSION);
+        storage::extend_instance(&e);
+        events::migrated(&e, version, CONTRACT_VERSION);
+        Ok(CONTRACT_VERSION)
+    }
+
+    /**
+    * Returns the version of the storage layout of the identity, 0 if it was deployed before the version
+    * was recorded.
+    */
+    pub fn get_version(e: Env) -> u32 {
+        get_version(&e)
+    }
+
+    /**
+    * Returns the TTL configuration of the identity, the default one if it was never set.
+    */
+    pub fn get_ttl_config(e: Env) -> TtlConfig {
+        storage::get_ttl_config(&e)
+    }
+
+    /**
+    * Extends the TTL of the instance, of every key and purpose and of every claim and claim topic
+    * of the identity. Anyone can call it, the caller paying for the extension.
+    * Executions are extended whenever they are read or written.
+    */
+    pub fn extend_identity_ttl(e: Env) {
+        storage::extend_instance(&e);
+
+        for purpose in storage::get_index(&e, &DataKey::Purposes) {
+            if let Some(keys) = storage::get_persistent::<Vec<BytesN<32>>>(&e, &DataKey::Purpose(purpose)) {
+                for key in keys {
+                    storage::extend_persistent(&e, &DataKey::Key(key));
+                }
+            }
+        }
+
+        for topic in storage::get_index(&e, &DataKey::ClaimTopics) {
+            if let Some(claim_ids) = storage::get_persistent::<Vec<BytesN<32>>>(&e, &DataKey::ClaimTopic(topic)) {
+                for claim_id in claim_ids {
+                    storage::extend_persistent(&e, &DataKey::Claim(claim_id));
+                }
+            }
+        }
+    }
+}
+
+#[contractimpl]
+impl IERC734 for Identity {
+    /**
+    * implementation of the addKey function of the ERC-734 standard
+    * Adds a _key to the identity. The _purpose specifies the purpose of key. Initially we propose four purposes:
+    * 1: MANAGEMENT keys, which can manage the identity
+    * 2: ACTION keys, which perform actions in this identities name (signing, logins, transactions, etc.)
+    * 3: CLAIM signer keys, used to sign claims on other identities which need to be revokable.
+    * 4: ENCRYPTION keys, used to encrypt data e.g. hold in claims.
+    * MUST only be done by keys of purpose 1, or the identity itself.
+    * If its the identity itself, the approval process will determine its approval.
+    * @param _caller address authorizing the call, either holding a MANAGEMENT key or the identity itself
+    * @param _key public key
+    * @param _type type of key used, which would be a uint256 for different key types. e.g. 1 = ECDSA, 2 = RSA, 3 = ED25519, etc.
+    * @param _purpose a uint256 specifying the key type, like 1 = MANAGEMENT, 2 = ACTION, 3 = CLAIM, 4 = ENCRYPTION
+    * @return success Returns TRUE if the addition was successful and FALSE if not
+    */
+    fn add_key(e: Env, caller: Address, key: BytesN<32>, purpose: u32, key_type: u32) -> Result<bool, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        store_key(&e, key, purpose, key_type, None)?;
+
+        Ok(true)
+    }
+
+    /**
+    * See {IERC734-approve}.
+    * Approves an execution.
+    * If the execution targets the identity itself, the caller must hold a MANAGEMENT key,
+    * otherwise an ACTION key. A rejected execution stays pending and can be approved later.
+    * @param _caller address authorizing the call
+    * @param _id the id of the execution to approve
+    * @param _approve TRUE to approve the execution, FALSE to reject it
+    * @return success Returns TRUE if the execution was performed successfully
+    */
+    fn approve(e: Env, caller: Address, execution_id: u64, approve: bool) -> Result<bool, OnChainIdError> {
+        let map_execution = DataKey::Execution(execution_id);
+        if let Some(mut retrieved_execution) = storage::get_persistent::<Execution>(&e, &map_execution) {
+            if retrieved_execution.executed {
+                return Err(OnChainIdError::ExecutionAlreadyExecuted);
+            }
+
+            if retrieved_execution.to == e.current_contract_address() {
+                require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+            } else {
+                require_key_purpose(&e, &caller, ACTION_KEY)?;
+            }
+
+            events::approved(&e, execution_id, approve);
+            retrieved_execution.approved = approve;
+            if approve {
+                retrieved_execution.executed = run_execution(&e, execution_id, &retrieved_execution);
+            }
+            storage::set_persistent(&e, &map_execution, &retrieved_execution);
+
+            Ok(retrieved_execution.executed)
+        } else {
+            Err(OnChainIdError::ExecutionNotFound)
+        }
+    }
+
+    /**
+    * See {IERC734-execute}.
+    * Passes an execution instruction to the identity.
+    * The execution is approved right away if the caller holds a MANAGEMENT key, or if it holds an
+    * ACTION key and the execution targets another contract. Otherwise it waits for {approve}.
+    * Executions to the identity itself support add_key, remove_key, add_claim and remove_claim,
+    * with the arguments of these functions except the caller.
+    * @param _caller address requesting the execution, which must authorize the call
+    * @param _to the contract to invoke, can be the identity itself
+    * @param _fnName the name of the function to invoke
+    * @param _args the arguments of the function to invoke
+    * @return executionId Returns the id of the execution
+    */
+    fn execute(e: Env, caller: Address, to: Address, fn_name: Symbol, args: Vec<Val>) -> Result<u64, OnChainIdError> {
+        caller.require_auth();
+
+        let execution_id: u64 = e.storage().instance().get(&DataKey::ExecutionNonce).unwrap_or(0);
+        e.storage().instance().set(&DataKey::ExecutionNonce, &(execution_id + 1));
+
+        let mut execution = Execution {
+            to,
+            fn_name,
+            args,
+            approved: false,
+            executed: false,
+        };
+        events::execution_requested(&e, execution_id, &execution);
+
+        let caller_key = address_to_key(&e, &caller);
+        let is_management_key = key_store::key_has_purpose(&e, &caller_key, MANAGEMENT_KEY).unwrap_or(false);
+        let is_action_key = key_store::key_has_purpose(&e, &caller_key, ACTION_KEY).unwrap_or(false);
+        if is_management_key || (execution.to != e.current_contract_address() && is_action_key) {
+            events::approved(&e, execution_id, true);
+            execution.approved = true;
+            execution.executed = run_execution(&e, execution_id, &execution);
+        }
+        storage::set_persistent(&e, &DataKey::Execution(execution_id), &execution);
+
+        Ok(execution_id)
+    }
+
+    /**
+    * See {IERC734-removeKey}.
+    * Remove the purpose from a key.
+    * MUST only be done by keys of purpose 1, or the identity itself.
+    */
+    fn remove_key(e: Env, caller: Address, key: BytesN<32>, purpose: u32) -> Result<bool, OnChainIdError> {
+        require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+
+        key_store::remove_key(&e, key, purpose)?;
+
+        Ok(true)
+    }
+
+    /**
+     * See {IERC734-getKey}.
+     * Implementation of the getKey function from the ERC-734 standard
+     * @param _key The public key. 
+     * @return purposes Returns the full key data, if present in the identity.
+     * @return keyType Returns the full key data, if present in the identity.
+     * @return key Returns the full key data, if present in the identity.
+     */
+    fn get_key(e: Env, key: BytesN<32>) -> Result<(Vec<u32>, u32, BytesN<32>), OnChainIdError>{
+        let retrieved_key = key_store::get_key(&e, &key)?;
+        Ok((retrieved_key.purposes, retrieved_key.key_type, retrieved_key.key))
+    }
+
+    /**
+    * See {IERC734-getKeyPurposes}.
+    * gets the purposes of a key
+    * @param _key The public key
+    * @return _purposes Returns the purposes of the specified key
+    */
+    fn get_key_purposes(e: Env, key: BytesN<32>) -> Result<Vec<u32>, OnChainIdError>{
+        Ok(key_store::get_key(&e, &key)?.purposes)
+    }
+
+    /**
+    * See {IERC734-getKeysByPurpose}.
+    * gets all the keys with a specific purpose from an identity
+    * @param _purpose a uint256[] Array of the key types, like 1 = MANAGEMENT, 2 = ACTION, 3 = CLAIM, 4 = ENCRYPTION
+    * @return keys Returns an array of public key held by this identity and having the specified purpose
+    */
+    fn get_keys_by_purpose(e: Env, purpose: u32) -> Vec<BytesN<32>>{
+        key_store::get_keys_by_purpose(&e, purpose)
+    }
+
+    /**
+    * See {IERC734-keyHasPurpose}.
+    * Checks if a key has a specific purpose. A MANAGEMENT key is considered to hold every purpose.
+    * @param _key The public key
+    * @param _purpose The purpose to check
+    * @return result Returns TRUE if the key has the purpose, FALSE otherwise
+    */
+    fn key_has_purpose(e: Env, key: BytesN<32>, purpose: u32) -> Result<bool, OnChainIdError>{
+        key_store::key_has_purpose(&e, &key, purpose)
+    }
+}  
+
+#[contractimpl]
+impl IERC735 for Identity {
+    /**
+    * See {IERC735-addClaim}.
+    * Implementation of the addClaim function from the ERC-735 standard
+    * Require that the caller holds a CLAIM key, or is the identity itself.
+    * The claim is checked with {IClaimIssuer-isClaimValid} of the issuer contract before it is stored,
+    * unless the identity is the issuer.
+    *
+    * @param _caller address authorizing the call
+    * @param _topic The type of claim
+    * @param _scheme The scheme with which this claim SHOULD be verified or how it should be processed.
+    * @param _issuer The address of the claim issuer, a ClaimIssuer contract or the identity itself.
+    * @param _signature Signature which is the proof that the claim issuer issued a claim of topic for this identity.
+    * @param _data The hash of the claim data, sitting in another location, a bit-mask, call data, or actual data.
+    * @param _uri The location of the claim, this can be HTTP links, swarm hashes, IPFS hashes, and such.
+    * @param _validFrom The ledger timestamp from which the claim is valid, 0 if valid right away.
+    * @param _validUntil The ledger timestamp until which the claim is valid, 0 if it does not expire.
+    *
+    * @return claimId Returns the id of the added or updated claim i.e. keccak256(_issuer, _topic)
+    */
+    fn add_claim(e: Env, caller: Address, topic: u128, scheme: u128, issuer: Address, signature: Bytes, data: Bytes, uri: String, valid_from: u64, valid_until: u64) -> Result<BytesN<32>, OnChainIdError>{
+        require_key_purpose(&e, &caller, CLAIM_SIGNER_KEY)?;
+
+        store_claim(&e, topic, scheme, issuer, signature, data, uri, valid_from, valid_until)
+    }
+
+    /**
+    * See {IERC735-getClaim}.
+    * Implementation of the getClaim function from the ERC-735 standard.
+    *
+    * @param _claimId The identity of the claim i.e. keccak256(abi.encode(_issuer, _topic))
+    *
+    * @return topic Returns all the parameters of the claim for the
+    * specified _claimId (topic, scheme, signature, issuer, data, uri) .
+    * @return scheme Returns all the parameters of the claim for the
+    * specified _claimId (topic, scheme, signature, issuer, data, uri) .
+    * @return issuer Returns all the parameters of the claim for the
+    * specified _claimId (topic, scheme, signature, issuer, data, uri) .
+    * @return signature Returns all the parameters of the claim for the
+    * specified _claimId (topic, scheme, signature, issuer, data, uri) .
+    * @return data Returns all the parameters of the claim for the
+    * specified _claimId (topic, scheme, signature, issuer, data, uri) .
+    * @return uri Returns all the parameters of the claim for the
+    * specified _claimId (topic, scheme, signature, issuer, data, uri) .
+    */
+    fn get_claim(e: Env, claim_id: BytesN<32>) -> Result<(u128, u128, Address, Bytes, Bytes, String), OnChainIdError>{
+        let map_key = DataKey::Claim(claim_id.clone());
+        if let Some(retrieved_claim) = storage::get_persistent::<Claim>(&e, &map_key) {
+            Ok((retrieved_claim.topic, retrieved_claim.scheme, retrieved_claim.issuer, retrieved_claim.signature, retrieved_claim.data, retrieved_claim.uri))
+        } else {
+            Err(OnChainIdError::NoClaimFound)
+        }
+    }
+
+    /**
+    * See {IERC735-removeClaim}.
+    * Implementation of the removeClaim function from the ERC-735 standard
+    * Can only be removed by the claim issuer, or the claim holder itself.
+    *
+    * @param _caller address authorizing the call, either the claim issuer, a MANAGEMENT key or the identity itself
+    * @param _claimId The identity of the claim i.e. keccak256(_issuer, _topic)
+    *
+    * @return success Returns TRUE when the claim was removed.
+    * triggers ClaimRemoved event
+    */
+    fn remove_claim(e: Env, caller: Address, claim_id: BytesN<32>) -> Result<bool, OnChainIdError>{
+        let map_key = DataKey::Claim(claim_id.clone());
+        if let Some(retrieved_claim) = storage::get_persistent::<Claim>(&e, &map_key) {
+            if caller == retrieved_claim.issuer {
+                caller.require_auth();
+            } else {
+                require_key_purpose(&e, &caller, MANAGEMENT_KEY)?;
+            }
+
+            remove_stored_claim(&e, &claim_id, &retrieved_claim);
+
+        } else {
+            return Err(OnChainIdError::NoClaimFound);
+        }
+        Ok(true)
+    }
+
+    /**
+    * See {IERC735-getClaimIdsByTopic}.
+    * Implementation of the getClaimIdsByTopic function from the ERC-735 standard.
+    * used to get all the claims from the specified topic
+    * @param _topic The identity of the claim i.e. keccak256(_issuer, _topic)
+    * @return claimIds Returns an array of claim IDs by topic.
+    */
+    fn get_claim_ids_by_topic(e: Env, topic: u128) -> Result<Vec<BytesN<32>>, OnChainIdError>
+    {
+        let map_key = DataKey::ClaimTopic(topic);
+        if let Some(retrieved_claim_topic) = storage::get_persistent::<Vec<BytesN<32>>>(&e, &map_key) {
+            Ok(retrieved_claim_topic)
+        } else {
+            Err(OnChainIdError::NoClaimTopicFound)
+        }
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for Identity {
+    type Signature = Vec<KeySignature>;
+    type Error = OnChainIdError;
+
+    /**
+     * Authorizes invocations on behalf of the identity, which can then be the caller of its own
+     * entrypoints. Each signature must be a valid signature of the payload by a key of the identity,
+     * of any supported key type. Invocations of the identity itself require MANAGEMENT keys,
+     * other invocations ACTION keys, as for {IERC734-execute}.
+     */
+    fn __check_auth(e: Env, signature_payload: Hash<32>, signatures: Vec<KeySignature>, auth_contexts: Vec<Context>) -> Result<(), OnChainIdError> {
+        if signatures.is_empty() {
+            return Err(OnChainIdError::Unauthorized);
+        }
+
+        let mut purpose = ACTION_KEY;
+        for context in auth_contexts {
+            match context {
+                Context::Contract(contract_context) if contract_context.contract != e.current_contract_address() => {}
+                _ => purpose = MANAGEMENT_KEY,
+            }
+        }
+
+        let payload: Bytes = signature_payload.to_bytes().into();
+        for key_signature in signatures {
+            let key = storage::get_persistent::<Key>(&e, &DataKey::Key(key_signature.key.clone())).ok_or(OnChainIdError::Unauthorized)?;
+            if !signature::verify(&e, &key, &payload, &key_signature.signature)? {
+                return Err(OnChainIdError::InvalidSignature);
+            }
+            if !key_store::key_has_purpose(&e, &key_signature.key, purpose)? {
+                return Err(OnChainIdError::Unauthorized);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1022 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::identity::{Identity, IdentityClient, CONTRACT_VERSION};
+use onchain_id::{erc734::{ED25519_KEY_TYPE, SECP256R1_KEY_TYPE}, error::OnChainIdError, events::KeyEvent, key_store::address_to_key, signature::secp256r1_key_hash, storage::{DEFAULT_TTL_EXTEND_TO, DEFAULT_TTL_THRESHOLD}, structs::{Claim, DataKey, Execution, KeySignature, TtlConfig}};
+use soroban_sdk::{ auth::{Context, ContractContext}, testutils::{storage::{Instance as _, Persistent as _}, Address as _, BytesN as _, Events, Ledger}, token, vec, xdr::{AccountId, PublicKey, ScAddress, ToXdr, Uint256}, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use ed25519_dalek::{Keypair, Signer};
+use rand::thread_rng;
+use claim_issuer::claim_issuer::{ClaimIssuer, ClaimIssuerClient};
+use p256::ecdsa::{signature::Signer as _, Signature as P256Signature, SigningKey as P256SigningKey};
+
+// Registers an identity initialized with the MANAGEMENT key of its owner address.
+fn create_identity(env: &Env) -> (IdentityClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, Identity);
+    let client = IdentityClient::new(env, &contract_id);
+
+    let owner = Address::generate(env);
+    client.initialize(&address_to_key(env, &owner), &owner);
+    (client, owner)
+}
+
+// Returns the Stellar account address controlled by an ed25519 key pair.
+fn account_address(env: &Env, key_pair: &Keypair) -> Address {
+    let account_id = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key_pair.public.to_bytes())));
+    Address::try_from_val(env, &ScAddress::Account(account_id)).unwrap()
+}
+
+// Returns the last `count` events published in the test environment.
+fn last_events(env: &Env, count: u32) -> Vec<(Address, Vec<Val>, Val)> {
+    let events = env.events().all();
+    events.slice(events.len() - count..)
+}
+
+// Returns the TTL of a persistent entry of the identity.
+fn persistent_ttl(env: &Env, identity: &Address, key: &DataKey) -> u32 {
+    env.as_contract(identity, || env.storage().persistent().get_ttl(key))
+}
+
+// Advances the ledger until entries extended to the default TTL fall below the default threshold.
+fn age_entries(env: &Env) {
+    env.ledger().with_mut(|ledger| ledger.sequence_number += DEFAULT_TTL_EXTEND_TO - DEFAULT_TTL_THRESHOLD + 1);
+}
+
+// Registers a claim issuer with an ed25519 CLAIM key, returns its client and the key pair of the CLAIM key.
+fn create_claim_issuer(env: &Env) -> (ClaimIssuerClient<'_>, Keypair) {
+    env.mock_all_auths();
+    // The claim issuer is registered at the address of its deployment by its owner
+    let owner = Address::generate(env);
+    let salt = BytesN::random(env);
+    let contract_id = env.register_contract(Some(&env.deployer().with_address(owner.clone(), salt.clone()).deployed_address()), ClaimIssuer);
+    let client = ClaimIssuerClient::new(env, &contract_id);
+    client.initialize(&address_to_key(env, &owner), &owner, &salt);
+    let claim_key_pair = Keypair::generate(&mut thread_rng());
+    client.add_key(&owner, &BytesN::from_array(env, claim_key_pair.public.as_bytes()), &3, &ED25519_KEY_TYPE);
+    (client, claim_key_pair)
+}
+
+// Signs the claim message (identity, topic, no validity window, data) with the issuer key pair.
+fn sign_claim(env: &Env, issuer_key_pair: &Keypair, identity: &Address, topic: u128, data: &Bytes) -> Bytes {
+    sign_claim_with_validity(env, issuer_key_pair, identity, topic, data, 0, 0)
+}
+
+// Signs the claim message (identity, topic, valid_from, valid_until, data) with the issuer key pair,
+// the signature being prefixed with the public key of the key pair.
+fn sign_claim_with_validity(env: &Env, issuer_key_pair: &Keypair, identity: &Address, topic: u128, data: &Bytes, valid_from: u64, valid_until: u64) -> Bytes {
+    let mut combined = Bytes::new(env);
+    combined.append(&Bytes::from_slice(env, &address_to_key(env, identity).to_array()));
+    combined.append(&Bytes::from_slice(env, &topic.to_be_bytes()));
+    combined.append(&Bytes::from_slice(env, &valid_from.to_be_bytes()));
+    combined.append(&Bytes::from_slice(env, &valid_until.to_be_bytes()));
+    combined.append(data);
+
+    let combined_vec: std::vec::Vec<u8> = combined.iter().collect();
+    let mut signature = Bytes::from_array(env, issuer_key_pair.public.as_bytes());
+    signature.extend_from_array(&issuer_key_pair.sign(&combined_vec).to_bytes());
+    signature
+}
+
+// Returns the SEC-1 encoded public key of a secp256r1 signing key.
+fn secp256r1_public_key(env: &Env, signing_key: &P256SigningKey) -> BytesN<65> {
+    let public_key = signing_key.verifying_key().to_encoded_point(false);
+    BytesN::from_array(env, public_key.as_bytes().try_into().unwrap())
+}
+
+// Signs sha256 of a message with a secp256r1 key, as a 64 bytes r || s signature with a low s.
+fn sign_secp256r1(env: &Env, signing_key: &P256SigningKey, message: &Bytes) -> Bytes {
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    let signature: P256Signature = signing_key.sign(&message);
+    let signature = signature.normalize_s().unwrap_or(signature);
+    Bytes::from_slice(env, &signature.to_bytes())
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let owner_key = address_to_key(&env, &owner);
+    assert_eq!(client.get_keys_by_purpose(&1), vec![&env, owner_key.clone()]);
+    assert!(client.key_has_purpose(&owner_key, &1));
+}
+
+#[test]
+fn test_initialize_twice() {
+    let env = Env::default();
+    let (client, _owner) = create_identity(&env);
+
+    let attacker = Address::generate(&env);
+    let initialize_result = client.try_initialize(&address_to_key(&env, &attacker), &attacker);
+    assert_eq!(initialize_result, Err(Ok(OnChainIdError::AlreadyInitialized)));
+    assert_eq!(client.try_get_key(&address_to_key(&env, &attacker)), Err(Ok(OnChainIdError::KeyNotRegistered)));
+}
+
+#[test]
+fn test_initialize_with_account_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = IdentityClient::new(&env, &env.register_contract(None, Identity));
+    let owner_key_pair = Keypair::generate(&mut thread_rng());
+    let owner = account_address(&env, &owner_key_pair);
+    let owner_key = BytesN::from_array(&env, owner_key_pair.public.as_bytes());
+
+    // An account owner can only register its own key
+    let initialize_result = client.try_initialize(&BytesN::random(&env), &owner);
+    assert_eq!(initialize_result, Err(Ok(OnChainIdError::OwnerKeyMismatch)));
+
+    client.initialize(&owner_key, &owner);
+    assert_eq!(client.get_keys_by_purpose(&1), vec![&env, owner_key]);
+}
+
+#[test]
+fn test_add_key_before_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, Identity);
+    let client = IdentityClient::new(&env, &contract_id);
+
+    // Without an owner nobody can take over the identity
+    let stranger = Address::generate(&env);
+    let add_key_result = client.try_add_key(&stranger, &address_to_key(&env, &stranger), &1, &ED25519_KEY_TYPE);
+    assert_eq!(add_key_result, Err(Ok(OnChainIdError::Unauthorized)));
+}
+
+#[test]
+fn test_add_key() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Add issuer key with purpose as claim signer - purpose 3. Key type 3 is ED25519
+    let issuer = BytesN::random(&env);
+    let add_key_result = client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+    assert!(add_key_result);
+}
+
+#[test]
+fn test_add_key_by_stranger() {
+    let env = Env::default();
+    let (client, _owner) = create_identity(&env);
+
+    // A caller without a MANAGEMENT key cannot add keys
+    let stranger = Address::generate(&env);
+    let add_key_result = client.try_add_key(&stranger, &address_to_key(&env, &stranger), &1, &ED25519_KEY_TYPE);
+    assert_eq!(add_key_result, Err(Ok(OnChainIdError::Unauthorized)));
+}
+
+#[test]
+fn test_add_key_by_claim_signer() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // A key with only the CLAIM purpose cannot manage keys
+    let signer = Address::generate(&env);
+    client.add_key(&owner, &address_to_key(&env, &signer), &3, &ED25519_KEY_TYPE);
+
+    let add_key_result = client.try_add_key(&signer, &BytesN::random(&env), &3, &ED25519_KEY_TYPE);
+    assert_eq!(add_key_result, Err(Ok(OnChainIdError::Unauthorized)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_add_key_without_auth() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    env.mock_auths(&[]);
+    client.add_key(&owner, &BytesN::random(&env), &3, &ED25519_KEY_TYPE);
+}
+
+#[test]
+fn test_remove_key_by_stranger() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let issuer = BytesN::random(&env);
+    client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+
+    let stranger = Address::generate(&env);
+    let remove_key_result = client.try_remove_key(&stranger, &issuer, &3);
+    assert_eq!(remove_key_result, Err(Ok(OnChainIdError::Unauthorized)));
+}
+
+#[test]
+fn test_get_key() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Add issuer key with purpose as claim signer - purpose 3. Key type 3 is ED25519
+    let issuer = BytesN::random(&env);
+    let add_key_result = client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+    assert!(add_key_result);
+
+    let get_key_result = client.get_key(&issuer);
+    assert_eq!(get_key_result, (vec![&env, 3], ED25519_KEY_TYPE, issuer.clone()));
+}
+
+#[test]
+fn test_get_key_purposes() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Add issuer key with purpose as claim signer - purpose 3. Key type 3 is ED25519
+    let issuer = BytesN::random(&env);
+    let add_key_result = client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+    assert!(add_key_result);
+
+    let get_key_purposes_result = client.get_key_purposes(&issuer);
+    assert_eq!(get_key_purposes_result, vec![&env, 3]);
+}
+
+#[test]
+fn test_get_keys_by_purpose() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Add issuer key with purpose as claim signer - purpose 3. Key type 3 is ED25519
+    let issuer = BytesN::random(&env);
+    let add_key_result = client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+    assert!(add_key_result);
+
+    let get_keys_by_purpose_result = client.get_keys_by_purpose(&3);
+    assert_eq!(get_keys_by_purpose_result, vec![&env, issuer.clone()]);
+}
+
+#[test]
+fn test_key_has_purpose() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Add issuer key with purpose as claim signer - purpose 3. Key type 3 is ED25519
+    let issuer = BytesN::random(&env);
+    let add_key_result = client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+    assert!(add_key_result);
+
+    let key_has_purpose_result = client.key_has_purpose(&issuer, &3);
+    assert!(key_has_purpose_result);
+}
+
+#[test]
+fn test_remove_key() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Add issuer key with purpose as claim signer - purpose 3. Key type 3 is ED25519
+    let issuer = BytesN::random(&env);
+    let add_key_result = client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+    assert!(add_key_result);
+
+    let remove_key_result = client.remove_key(&owner, &issuer, &3);
+    assert!(remove_key_result);
+    assert_eq!(client.get_keys_by_purpose(&3), vec![&env]);
+    assert_eq!(client.try_remove_key(&owner, &issuer, &3), Err(Ok(OnChainIdError::KeyNotRegistered)));
+
+    // Removing a purpose the key does not hold fails without changing the key
+    client.add_key(&owner, &issuer, &2, &ED25519_KEY_TYPE);
+    assert_eq!(client.try_remove_key(&owner, &issuer, &3), Err(Ok(OnChainIdError::KeyDoesntHavePurpose)));
+    assert_eq!(client.get_key_purposes(&issuer), vec![&env, 2]);
+
+    // as does a key missing from the keys of the purpose
+    env.as_contract(&client.address, || env.storage().persistent().set(&DataKey::Purpose(2), &Vec::<BytesN<32>>::new(&env)));
+    assert_eq!(client.try_remove_key(&owner, &issuer, &2), Err(Ok(OnChainIdError::KeyDoesntHavePurpose)));
+    assert_eq!(client.get_key_purposes(&issuer), vec![&env, 2]);
+}
+
+#[test]
+fn test_add_claim() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Claim issuer contract with an ed25519 CLAIM key
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    //Add KYC claim
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let identity_key: BytesN<32> = address_to_key(&env, &client.address);
+
+    let mut combined = Bytes::new(&env);
+    combined.append(&Bytes::from_slice(&env, &identity_key.to_array()));
+    combined.append(&Bytes::from_slice(&env, &1010101_u128.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&data);
+
+    // The signature is prefixed with the CLAIM key of the issuer which signed it
+    let combined_vec: std::vec::Vec<u8> = combined.iter().collect();
+    let mut signature = Bytes::from_array(&env, issuer_key_pair.public.as_bytes());
+    signature.extend_from_array(&issuer_key_pair.sign(&combined_vec).to_bytes());
+
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let get_claim_result = client.get_claim(&claim_id);
+    assert_eq!(get_claim_result, (1010101, 1, issuer.clone(), signature, data, String::from_str(&env, "")));
+}
+
+#[test]
+fn test_add_claim_with_catalogue_topic() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    // FIRST_NAME_CLEAR of the topic catalogue does not fit in a u32
+    let topic: u128 = 10101000042003;
+    let data = Bytes::from_slice(&env, "firstName=Alice".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, topic, &data);
+    let claim_id = client.add_claim(&owner, &topic, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    assert_eq!(client.get_claim_ids_by_topic(&topic), vec![&env, claim_id.clone()]);
+    assert_eq!(client.get_claim(&claim_id).0, topic);
+}
+
+#[test]
+fn test_add_claim_by_claim_signer() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    // The claim signer account holds a CLAIM key of the identity and adds the claim itself
+    let claim_signer_key_pair = Keypair::generate(&mut thread_rng());
+    client.add_key(&owner, &BytesN::from_array(&env, claim_signer_key_pair.public.as_bytes()), &3, &ED25519_KEY_TYPE);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &data);
+    let claim_signer = account_address(&env, &claim_signer_key_pair);
+    let claim_id = client.add_claim(&claim_signer, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let get_claim_ids_by_topic_result = client.get_claim_ids_by_topic(&1010101);
+    assert_eq!(get_claim_ids_by_topic_result, vec![&env, claim_id]);
+}
+
+#[test]
+fn test_add_claim_by_stranger() {
+    let env = Env::default();
+    let (client, _owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &data);
+    let stranger = Address::generate(&env);
+    let add_claim_result = client.try_add_claim(&stranger, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::Unauthorized)));
+}
+
+#[test]
+fn test_add_claim_with_invalid_signature() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    // The signature covers different data, so the claim must not be stored
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &Bytes::from_slice(&env, "false".as_bytes()));
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert!(add_claim_result.is_err());
+
+    let get_claim_ids_by_topic_result = client.try_get_claim_ids_by_topic(&1010101);
+    assert_eq!(get_claim_ids_by_topic_result, Err(Ok(OnChainIdError::NoClaimTopicFound)));
+}
+
+#[test]
+fn test_add_claim_with_unregistered_issuer() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, _issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    // The signing key was never added to the issuer as a CLAIM key
+    let signing_key_pair = Keypair::generate(&mut thread_rng());
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &signing_key_pair, &client.address, 1010101, &data);
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::IssuerKeyNotAuthorized)));
+
+    // The issuer must be a claim issuer contract
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &Address::generate(&env), &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::InvalidClaim)));
+}
+
+#[test]
+fn test_add_self_issued_claim() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Claims issued by the identity itself are not checked by a claim issuer
+    let issuer = client.address.clone();
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &Bytes::new(&env), &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(client.get_claim(&claim_id).2, issuer);
+}
+
+#[test]
+fn test_get_claim_ids_by_topic() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Claim issuer contract with an ed25519 CLAIM key
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    //Add KYC claim
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let identity_key: BytesN<32> = address_to_key(&env, &client.address);
+
+    let mut combined = Bytes::new(&env);
+    combined.append(&Bytes::from_slice(&env, &identity_key.to_array()));
+    combined.append(&Bytes::from_slice(&env, &1010101_u128.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&data);
+
+    // The signature is prefixed with the CLAIM key of the issuer which signed it
+    let combined_vec: std::vec::Vec<u8> = combined.iter().collect();
+    let mut signature = Bytes::from_array(&env, issuer_key_pair.public.as_bytes());
+    signature.extend_from_array(&issuer_key_pair.sign(&combined_vec).to_bytes());
+
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let get_claim_ids_by_topic_result = client.get_claim_ids_by_topic(&1010101);
+    assert_eq!(get_claim_ids_by_topic_result, vec![&env, claim_id]);
+}
+
+#[test]
+fn test_remove_claim() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Claim issuer contract with an ed25519 CLAIM key
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    //Add KYC claim
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let identity_key: BytesN<32> = address_to_key(&env, &client.address);
+
+    let mut combined = Bytes::new(&env);
+    combined.append(&Bytes::from_slice(&env, &identity_key.to_array()));
+    combined.append(&Bytes::from_slice(&env, &1010101_u128.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&data);
+
+    // The signature is prefixed with the CLAIM key of the issuer which signed it
+    let combined_vec: std::vec::Vec<u8> = combined.iter().collect();
+    let mut signature = Bytes::from_array(&env, issuer_key_pair.public.as_bytes());
+    signature.extend_from_array(&issuer_key_pair.sign(&combined_vec).to_bytes());
+
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let remove_claim_result = client.remove_claim(&owner, &claim_id);
+    assert!(remove_claim_result);
+    assert_eq!(client.get_claim_ids_by_topic(&1010101), vec![&env]);
+}
+
+#[test]
+fn test_remove_claim_by_issuer() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &data);
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    // The issuer of the claim can revoke it from the identity
+    let remove_claim_result = client.remove_claim(&issuer_client.address, &claim_id);
+    assert!(remove_claim_result);
+    assert_eq!(client.try_get_claim(&claim_id), Err(Ok(OnChainIdError::NoClaimFound)));
+}
+
+#[test]
+fn test_remove_claim_by_stranger() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &data);
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let stranger = Address::generate(&env);
+    let remove_claim_result = client.try_remove_claim(&stranger, &claim_id);
+    assert_eq!(remove_claim_result, Err(Ok(OnChainIdError::Unauthorized)));
+}
+
+#[test]
+fn is_claim_valid() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Claim issuer contract with an ed25519 CLAIM key
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    //Add KYC claim
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let identity_key: BytesN<32> = address_to_key(&env, &client.address);
+
+    let mut combined = Bytes::new(&env);
+    combined.append(&Bytes::from_slice(&env, &identity_key.to_array()));
+    combined.append(&Bytes::from_slice(&env, &1010101_u128.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    combined.append(&data);
+
+    // The signature is prefixed with the CLAIM key of the issuer which signed it
+    let combined_vec: std::vec::Vec<u8> = combined.iter().collect();
+    let mut signature = Bytes::from_array(&env, issuer_key_pair.public.as_bytes());
+    signature.extend_from_array(&issuer_key_pair.sign(&combined_vec).to_bytes());
+
+    let _claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let is_claim_valid_result = issuer_client.is_claim_valid(&client.address, &1010101, &signature, &data, &0, &0);
+    assert!(is_claim_valid_result);
+}
+
+#[test]
+fn test_execute_add_key_by_management_key() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Executions of a MANAGEMENT key on the identity itself are approved right away
+    let issuer = BytesN::random(&env);
+    let args = vec![&env, issuer.into_val(&env), 3_u32.into_val(&env), 1_u32.into_val(&env)];
+    let execution_id = client.execute(&owner, &client.address, &Symbol::new(&env, "add_key"), &args);
+    assert_eq!(execution_id, 0);
+    assert_eq!(client.get_key_purposes(&issuer), vec![&env, 3]);
+}
+
+#[test]
+fn test_execute_add_key_requires_approval() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let action = Address::generate(&env);
+    client.add_key(&owner, &address_to_key(&env, &action), &2, &ED25519_KEY_TYPE);
+
+    // An ACTION key cannot manage the identity, so the execution waits for approval
+    let issuer = BytesN::random(&env);
+    let args = vec![&env, issuer.into_val(&env), 3_u32.into_val(&env), 1_u32.into_val(&env)];
+    let execution_id = client.execute(&action, &client.address, &Symbol::new(&env, "add_key"), &args);
+    assert_eq!(client.try_get_key(&issuer), Err(Ok(OnChainIdError::KeyNotRegistered)));
+
+    assert_eq!(client.try_approve(&action, &execution_id, &true), Err(Ok(OnChainIdError::Unauthorized)));
+    assert!(!client.approve(&owner, &execution_id, &false));
+    assert_eq!(client.try_get_key(&issuer), Err(Ok(OnChainIdError::KeyNotRegistered)));
+
+    assert!(client.approve(&owner, &execution_id, &true));
+    assert_eq!(client.get_key_purposes(&issuer), vec![&env, 3]);
+    assert_eq!(client.try_approve(&owner, &execution_id, &true), Err(Ok(OnChainIdError::ExecutionAlreadyExecuted)));
+}
+
+#[test]
+fn test_execute_token_transfer_by_action_key() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin);
+    let token = token::Client::new(&env, &token_contract.address());
+    token::StellarAssetClient::new(&env, &token_contract.address()).mint(&client.address, &1000);
+
+    let action = Address::generate(&env);
+    client.add_key(&owner, &address_to_key(&env, &action), &2, &ED25519_KEY_TYPE);
+
+    // An ACTION key can make the identity transfer the tokens it holds
+    let recipient = Address::generate(&env);
+    let args = vec![&env, client.address.into_val(&env), recipient.into_val(&env), 400_i128.into_val(&env)];
+    client.execute(&action, &token.address, &Symbol::new(&env, "transfer"), &args);
+    assert_eq!(token.balance(&client.address), 600);
+    assert_eq!(token.balance(&recipient), 400);
+}
+
+#[test]
+fn test_execute_by_stranger() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // Anyone can request an execution, but it only runs once approved
+    let stranger = Address::generate(&env);
+    let args = vec![&env, address_to_key(&env, &stranger).into_val(&env), 1_u32.into_val(&env), 1_u32.into_val(&env)];
+    let execution_id = client.execute(&stranger, &client.address, &Symbol::new(&env, "add_key"), &args);
+    assert_eq!(client.try_approve(&stranger, &execution_id, &true), Err(Ok(OnChainIdError::Unauthorized)));
+    assert_eq!(client.try_get_key(&address_to_key(&env, &stranger)), Err(Ok(OnChainIdError::KeyNotRegistered)));
+
+    assert_eq!(client.try_approve(&owner, &(execution_id + 1), &true), Err(Ok(OnChainIdError::ExecutionNotFound)));
+}
+
+#[test]
+fn test_execute_unsupported_function() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    // The execution is approved but fails, so it can still be approved again
+    let execution_id = client.execute(&owner, &client.address, &Symbol::new(&env, "initialize"), &vec![&env]);
+    assert!(!client.approve(&owner, &execution_id, &true));
+}
+
+#[test]
+fn test_key_events() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let issuer = BytesN::random(&env);
+    client.add_key(&owner, &issuer, &3, &ED25519_KEY_TYPE);
+    let key_event = KeyEvent { key: issuer.clone(), purpose: 3, key_type: ED25519_KEY_TYPE };
+    assert_eq!(
+        last_events(&env, 1),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "KeyAdded"), issuer.clone(), 3_u32).into_val(&env), key_event.clone().into_val(&env))]
+    );
+
+    client.remove_key(&owner, &issuer, &3);
+    assert_eq!(
+        last_events(&env, 1),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "KeyRemoved"), issuer.clone(), 3_u32).into_val(&env), key_event.into_val(&env))]
+    );
+}
+
+#[test]
+fn test_claim_events() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &data);
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    let claim = Claim { topic: 1010101, scheme: 1, issuer: issuer.clone(), signature, data, uri: String::from_str(&env, ""), valid_from: 0, valid_until: 0 };
+    assert_eq!(
+        last_events(&env, 1),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "ClaimAdded"), claim_id.clone(), 1010101_u128).into_val(&env), claim.into_val(&env))]
+    );
+
+    // Adding a claim with the same issuer and topic updates it
+    let new_data = Bytes::from_slice(&env, "false".as_bytes());
+    let new_signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &new_data);
+    client.add_claim(&owner, &1010101, &1, &issuer, &new_signature, &new_data, &String::from_str(&env, ""), &0, &0);
+    let new_claim = Claim { topic: 1010101, scheme: 1, issuer, signature: new_signature, data: new_data, uri: String::from_str(&env, ""), valid_from: 0, valid_until: 0 };
+    assert_eq!(
+        last_events(&env, 1),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "ClaimChanged"), claim_id.clone(), 1010101_u128).into_val(&env), new_claim.clone().into_val(&env))]
+    );
+
+    client.remove_claim(&owner, &claim_id);
+    assert_eq!(
+        last_events(&env, 1),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "ClaimRemoved"), claim_id, 1010101_u128).into_val(&env), new_claim.into_val(&env))]
+    );
+}
+
+#[test]
+fn test_execution_events() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let issuer = BytesN::random(&env);
+    let fn_name = Symbol::new(&env, "add_key");
+    let args = vec![&env, issuer.into_val(&env), 3_u32.into_val(&env), 1_u32.into_val(&env)];
+    let execution_id = client.execute(&owner, &client.address, &fn_name, &args);
+
+    let requested = Execution { to: client.address.clone(), fn_name, args, approved: false, executed: false };
+    let mut approved = requested.clone();
+    approved.approved = true;
+    assert_eq!(
+        last_events(&env, 4),
+        vec![
+            &env,
+            (client.address.clone(), (Symbol::new(&env, "ExecutionRequested"), execution_id, client.address.clone()).into_val(&env), requested.into_val(&env)),
+            (client.address.clone(), (Symbol::new(&env, "Approved"), execution_id).into_val(&env), true.into_val(&env)),
+            (client.address.clone(), (Symbol::new(&env, "KeyAdded"), issuer.clone(), 3_u32).into_val(&env), KeyEvent { key: issuer, purpose: 3, key_type: 1 }.into_val(&env)),
+            (client.address.clone(), (Symbol::new(&env, "Executed"), execution_id, client.address.clone()).into_val(&env), approved.into_val(&env)),
+        ]
+    );
+}
+
+#[test]
+fn test_ttl_extended_on_write() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let owner_key = address_to_key(&env, &owner);
+    assert_eq!(persistent_ttl(&env, &client.address, &DataKey::Key(owner_key)), DEFAULT_TTL_EXTEND_TO);
+    assert_eq!(persistent_ttl(&env, &client.address, &DataKey::Purpose(1)), DEFAULT_TTL_EXTEND_TO);
+    assert_eq!(env.as_contract(&client.address, || env.storage().instance().get_ttl()), DEFAULT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_ttl_extended_on_read() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let owner_key = address_to_key(&env, &owner);
+    age_entries(&env);
+    assert_eq!(persistent_ttl(&env, &client.address, &DataKey::Key(owner_key.clone())), DEFAULT_TTL_THRESHOLD - 1);
+
+    client.get_key(&owner_key);
+    assert_eq!(persistent_ttl(&env, &client.address, &DataKey::Key(owner_key)), DEFAULT_TTL_EXTEND_TO);
+    // Entries which were not read keep their TTL
+    assert_eq!(persistent_ttl(&env, &client.address, &DataKey::Purpose(1)), DEFAULT_TTL_THRESHOLD - 1);
+}
+
+#[test]
+fn test_set_ttl_config() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    assert_eq!(client.get_ttl_config(), TtlConfig { threshold: DEFAULT_TTL_THRESHOLD, extend_to: DEFAULT_TTL_EXTEND_TO });
+
+    client.set_ttl_config(&owner, &5000, &10000);
+    assert_eq!(client.get_ttl_config(), TtlConfig { threshold: 5000, extend_to: 10000 });
+
+    let key = BytesN::random(&env);
+    client.add_key(&owner, &key, &2, &ED25519_KEY_TYPE);
+    assert_eq!(persistent_ttl(&env, &client.address, &DataKey::Key(key)), 10000);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(client.try_set_ttl_config(&stranger, &1000, &5000), Err(Ok(OnChainIdError::Unauthorized)));
+    assert_eq!(client.try_set_ttl_config(&owner, &5000, &1000), Err(Ok(OnChainIdError::InvalidTtlConfig)));
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    assert_eq!(client.try_set_ttl_config(&owner, &1000, &(max_ttl + 1)), Err(Ok(OnChainIdError::InvalidTtlConfig)));
+}
+
+#[test]
+fn test_extend_identity_ttl() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let claim_signer = BytesN::random(&env);
+    client.add_key(&owner, &claim_signer, &3, &ED25519_KEY_TYPE);
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &data);
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    age_entries(&env);
+    client.extend_identity_ttl();
+
+    let entries = [
+        DataKey::Key(address_to_key(&env, &owner)),
+        DataKey::Key(claim_signer),
+        DataKey::Purpose(1),
+        DataKey::Purpose(3),
+        DataKey::Claim(claim_id),
+        DataKey::ClaimTopic(1010101),
+    ];
+    for entry in entries.iter() {
+        assert_eq!(persistent_ttl(&env, &client.address, entry), DEFAULT_TTL_EXTEND_TO);
+    }
+    assert_eq!(env.as_contract(&client.address, || env.storage().instance().get_ttl()), DEFAULT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_claim_id_of_issuer_address() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &client.address, 1010101, &data);
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer_client.address, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let mut claim_id_bytes = issuer_client.address.clone().to_xdr(&env);
+    claim_id_bytes.append(&1010101_u128.to_xdr(&env));
+    assert_eq!(claim_id, env.crypto().keccak256(&claim_id_bytes).to_bytes());
+
+    // A claim signed by another CLAIM key of the issuer replaces the claim of the same issuer and topic
+    let new_key_pair = Keypair::generate(&mut thread_rng());
+    issuer_client.add_key(&issuer_client.address, &BytesN::from_array(&env, new_key_pair.public.as_bytes()), &3, &ED25519_KEY_TYPE);
+    let new_signature = sign_claim(&env, &new_key_pair, &client.address, 1010101, &data);
+    let new_claim_id = client.add_claim(&owner, &1010101, &1, &issuer_client.address, &new_signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert_eq!(new_claim_id, claim_id);
+    assert_eq!(client.get_claim_ids_by_topic(&1010101), vec![&env, claim_id]);
+}
+
+#[test]
+fn test_claim_message() {
+    let env = Env::default();
+    let (client, _owner) = create_identity(&env);
+
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let mut expected = Bytes::from_slice(&env, &address_to_key(&env, &client.address).to_array());
+    expected.append(&Bytes::from_slice(&env, &1010101_u128.to_be_bytes()));
+    expected.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    expected.append(&Bytes::from_slice(&env, &0_u64.to_be_bytes()));
+    expected.append(&data);
+    assert_eq!(client.claim_message(&1010101, &data, &0, &0), expected);
+}
+
+#[test]
+fn test_add_claim_signed_for_another_identity() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+    let (other_client, other_owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    // The claim issued to the other identity cannot be replayed on this one
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &other_client.address, 1010101, &data);
+    other_client.add_claim(&other_owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert!(client.try_add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0).is_err());
+    assert!(issuer_client.try_is_claim_valid(&client.address, &1010101, &signature, &data, &0, &0).is_err());
+}
+
+#[test]
+fn test_add_secp256r1_key() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let signing_key = P256SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng);
+    let public_key = secp256r1_public_key(&env, &signing_key);
+    let key = client.add_secp256r1_key(&owner, &public_key, &2);
+
+    assert_eq!(key, secp256r1_key_hash(&env, &public_key));
+    assert_eq!(client.get_key(&key), (vec![&env, 2], SECP256R1_KEY_TYPE, key.clone()));
+    assert_eq!(client.get_public_key(&key), public_key);
+
+    // A secp256r1 key cannot be verified without its public key
+    let add_key_result = client.try_add_key(&owner, &BytesN::random(&env), &2, &SECP256R1_KEY_TYPE);
+    assert_eq!(add_key_result, Err(Ok(OnChainIdError::PublicKeyRequired)));
+    assert_eq!(client.try_get_public_key(&address_to_key(&env, &owner)), Err(Ok(OnChainIdError::PublicKeyRequired)));
+}
+
+#[test]
+fn test_check_auth_with_secp256r1_key() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let management_signing_key = P256SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng);
+    let management_key = client.add_secp256r1_key(&owner, &secp256r1_public_key(&env, &management_signing_key), &1);
+    let action_signing_key = P256SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng);
+    let action_key = client.add_secp256r1_key(&owner, &secp256r1_public_key(&env, &action_signing_key), &2);
+
+    let payload = BytesN::random(&env);
+    let identity_context = vec![&env, Context::Contract(ContractContext {
+        contract: client.address.clone(),
+        fn_name: Symbol::new(&env, "add_key"),
+        args: vec![&env],
+    })];
+    let token_context = vec![&env, Context::Contract(ContractContext {
+        contract: Address::generate(&env),
+        fn_name: Symbol::new(&env, "transfer"),
+        args: vec![&env],
+    })];
+    let management_signature = vec![&env, KeySignature { key: management_key, signature: sign_secp256r1(&env, &management_signing_key, &payload.clone().into()) }];
+    let action_signature = vec![&env, KeySignature { key: action_key.clone(), signature: sign_secp256r1(&env, &action_signing_key, &payload.clone().into()) }];
+
+    // MANAGEMENT keys authorize any invocation, ACTION keys only invocations of other contracts
+    assert_eq!(env.try_invoke_contract_check_auth::<OnChainIdError>(&client.address, &payload, management_signature.into_val(&env), &identity_context), Ok(()));
+    assert_eq!(env.try_invoke_contract_check_auth::<OnChainIdError>(&client.address, &payload, action_signature.clone().into_val(&env), &token_context), Ok(()));
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<OnChainIdError>(&client.address, &payload, action_signature.into_val(&env), &identity_context),
+        Err(Ok(OnChainIdError::Unauthorized))
+    );
+
+    // A signature of another payload is rejected
+    let other_payload: BytesN<32> = BytesN::random(&env);
+    let other_signature = vec![&env, KeySignature { key: action_key, signature: sign_secp256r1(&env, &action_signing_key, &other_payload.into()) }];
+    assert!(env.try_invoke_contract_check_auth::<OnChainIdError>(&client.address, &payload, other_signature.into_val(&env), &token_context).is_err());
+}
+
+#[test]
+fn test_claim_validity() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    // The claim is valid between 2_000 and 3_000, the issuer only validates it within this window
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim_with_validity(&env, &issuer_key_pair, &client.address, 1010101, &data, 2_000, 3_000);
+    assert!(!issuer_client.is_claim_valid(&client.address, &1010101, &signature, &data, &2_000, &3_000));
+
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &2_000, &3_000);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::ClaimNotYetValid)));
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_500);
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &2_000, &3_000);
+    assert_eq!(client.get_claim_validity(&claim_id), (2_000, 3_000));
+    assert!(issuer_client.is_claim_valid(&client.address, &1010101, &signature, &data, &2_000, &3_000));
+    assert_eq!(client.get_valid_claims_by_topic(&1010101), vec![&env, claim_id.clone()]);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 3_001);
+    assert!(!issuer_client.is_claim_valid(&client.address, &1010101, &signature, &data, &2_000, &3_000));
+    assert_eq!(client.get_valid_claims_by_topic(&1010101), vec![&env]);
+    assert_eq!(client.get_claim_ids_by_topic(&1010101), vec![&env, claim_id]);
+
+    // Expired claims cannot be added
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &2_000, &3_000);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::ClaimExpired)));
+}
+
+#[test]
+fn test_claim_not_valid_yet_is_not_stored() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let (other_issuer_client, _) = create_claim_issuer(&env);
+
+    // Neither a claim of the issuer nor a claim forged for another issuer is stored before its validity window
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim_with_validity(&env, &issuer_key_pair, &client.address, 1010101, &data, 2_000, 0);
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &issuer_client.address, &signature, &data, &String::from_str(&env, ""), &2_000, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::ClaimNotYetValid)));
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &other_issuer_client.address, &signature, &data, &String::from_str(&env, ""), &2_000, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::ClaimNotYetValid)));
+
+    // Once valid, the forged claim is rejected by the other issuer
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_000);
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &other_issuer_client.address, &signature, &data, &String::from_str(&env, ""), &2_000, &0);
+    assert_eq!(add_claim_result, Err(Ok(OnChainIdError::IssuerKeyNotAuthorized)));
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer_client.address, &signature, &data, &String::from_str(&env, ""), &2_000, &0);
+    assert_eq!(client.get_claim_ids_by_topic(&1010101), vec![&env, claim_id.clone()]);
+    assert_eq!(client.get_valid_claims_by_topic(&1010101), vec![&env, claim_id]);
+}
+
+#[test]
+fn test_claim_validity_is_signed() {
+    let env = Env::default();
+    let (client, owner) = create_identity(&env);
+
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let issuer = issuer_client.address.clone();
+
+    // The validity window cannot be extended without a new signature of the issuer
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim_with_validity(&env, &issuer_key_pair, &client.address, 1010101, &data, 0, 3_000);
+    let add_claim_result = client.try_add_claim(&owner, &1010101, &1, &issuer, &signature, &data, &String::from_str(&env, ""), &0, &0);
+    assert!(add_claim_result.is_err());
+}
+
+// The identity contract built with `stellar contract build`, which identities are upgraded to.
+mod identity_contract {
+    use soroban_sdk::auth::Context;
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/identity.wasm");
+}
+
+#[test]
+fn test_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    // Identity deployed from the wasm, holding keys and a claim
+    let contract_id = env.register_contract_wasm(None, identity_contract::WASM);
+    let client = identity_contract::Client::new(&env, &contract_id);
+    let owner = Address::generate(&env);
+    client.initialize(&address_to_key(&env, &owner), &owner);
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+
+    let action_key = BytesN::random(&env);
+    client.add_key(&owner, &action_key, &2, &ED25519_KEY_TYPE);
+    let (issuer_client, issuer_key_pair) = create_claim_issuer(&env);
+    let data = Bytes::from_slice(&env, "true".as_bytes());
+    let signature = sign_claim(&env, &issuer_key_pair, &contract_id, 1010101, &data);
+    let claim_id = client.add_claim(&owner, &1010101, &1, &issuer_client.address, &signature, &data, &String::from_str(&env, ""), &0, &0);
+
+    let stranger = Address::generate(&env);
+    let new_wasm_hash = env.deployer().upload_contract_wasm(identity_contract::WASM);
+    assert_eq!(
+        client.try_upgrade(&stranger, &new_wasm_hash),
+        Err(Ok(identity_contract::OnChainIdError::Unauthorized))
+    );
+
+    client.upgrade(&owner, &new_wasm_hash);
+    assert_eq!(
+        last_events(&env, 1),
+        vec![&env, (contract_id.clone(), (Symbol::new(&env, "Upgraded"),).into_val(&env), new_wasm_hash.into_val(&env))]
+    );
+
+    // The upgraded identity migrates its storage, already at the current version, and keeps its keys and claims
+    assert_eq!(
+        client.try_migrate(&stranger),
+        Err(Ok(identity_contract::OnChainIdError::Unauthorized))
+    );
+    assert_eq!(client.migrate(&owner), CONTRACT_VERSION);
+    assert!(client.key_has_purpose(&action_key, &2));
+    assert!(client.key_has_purpose(&address_to_key(&env, &owner), &1));
+    assert_eq!(client.get_claim_ids_by_topic(&1010101), vec![&env, claim_id.clone()]);
+    assert_eq!(client.get_claim(&claim_id).2, issuer_client.address);
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum IdentityRegistryError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    AgentAlreadyAdded = 4,
+    AgentNotAdded = 5,
+    WalletAlreadyRegistered = 6,
+    WalletNotRegistered = 7
+}
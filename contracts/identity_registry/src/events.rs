@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/**
+ * Emitted when an agent is added to the registry.
+ * Topics: ("AgentAdded", agent). Data: ().
+ */
+pub(crate) fn agent_added(e: &Env, agent: &Address) {
+    e.events().publish((Symbol::new(e, "AgentAdded"), agent.clone()), ());
+}
+
+/**
+ * Emitted when an agent is removed from the registry.
+ * Topics: ("AgentRemoved", agent). Data: ().
+ */
+pub(crate) fn agent_removed(e: &Env, agent: &Address) {
+    e.events().publish((Symbol::new(e, "AgentRemoved"), agent.clone()), ());
+}
+
+/**
+ * Emitted when a wallet is registered with an identity.
+ * Topics: ("IdentityRegistered", wallet, identity). Data: ().
+ */
+pub(crate) fn identity_registered(e: &Env, wallet: &Address, identity: &Address) {
+    e.events().publish((Symbol::new(e, "IdentityRegistered"), wallet.clone(), identity.clone()), ());
+}
+
+/**
+ * Emitted when the identity of a wallet is replaced.
+ * Topics: ("IdentityUpdated", old_identity, new_identity). Data: ().
+ */
+pub(crate) fn identity_updated(e: &Env, old_identity: &Address, new_identity: &Address) {
+    e.events().publish((Symbol::new(e, "IdentityUpdated"), old_identity.clone(), new_identity.clone()), ());
+}
+
+/**
+ * Emitted when the country of a wallet is updated.
+ * Topics: ("CountryUpdated", wallet, country). Data: ().
+ */
+pub(crate) fn country_updated(e: &Env, wallet: &Address, country: u32) {
+    e.events().publish((Symbol::new(e, "CountryUpdated"), wallet.clone(), country), ());
+}
+
+/**
+ * Emitted when a wallet is removed from the registry.
+ * Topics: ("IdentityRemoved", wallet, identity). Data: ().
+ */
+pub(crate) fn identity_removed(e: &Env, wallet: &Address, identity: &Address) {
+    e.events().publish((Symbol::new(e, "IdentityRemoved"), wallet.clone(), identity.clone()), ());
+}
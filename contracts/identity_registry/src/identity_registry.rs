@@ -0,0 +1,264 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+use onchain_id::{
+    claim_topics_registry::ClaimTopicsRegistryClient, identity_registry::IIdentityRegistry, storage::{extend_instance, get_persistent, set_persistent},
+    structs::ClaimStatus, trusted_issuers_registry::TrustedIssuersRegistryClient, verifier,
+};
+
+use crate::{error::IdentityRegistryError, events};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    ClaimTopicsRegistry,
+    TrustedIssuersRegistry,
+    Agent(Address),
+    Investor(Address),
+}
+
+/**
+ * Identity and country a wallet is registered with.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Investor {
+    pub identity: Address,
+    pub country: u32,
+}
+
+/**
+ * Registry of the investors of the permissioned tokens, mirroring the IdentityRegistry contract of ERC-3643.
+ * The agents of the registry register the wallet of each investor with its identity and its ISO-3166
+ * numeric country code. A wallet is verified when its identity holds a valid claim of each topic of the
+ * claim topics registry, issued by an issuer the trusted issuers registry trusts for the topic.
+ */
+#[contract]
+pub struct IdentityRegistry;
+
+#[contractimpl]
+impl IdentityRegistry {
+    /**
+    * Initializes the registry with its admin and the registries the wallets are verified against.
+    * Can only be called once, right after the deployment of the registry.
+    * @param _admin address managing the agents, which must authorize the initialization
+    * @param _claimTopicsRegistry the registry of the claim topics required from the identities
+    * @param _trustedIssuersRegistry the registry of the issuers trusted for these topics
+    */
+    pub fn initialize(e: Env, admin: Address, claim_topics_registry: Address, trusted_issuers_registry: Address) -> Result<(), IdentityRegistryError> {
+        if e.storage().instance().has(&DataKey::Admin) {
+            return Err(IdentityRegistryError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::ClaimTopicsRegistry, &claim_topics_registry);
+        e.storage().instance().set(&DataKey::TrustedIssuersRegistry, &trusted_issuers_registry);
+        extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Adds an agent, allowed to register the investors.
+    * MUST only be done by the admin of the registry.
+    * @param _agent the address of the agent
+    */
+    pub fn add_agent(e: Env, agent: Address) -> Result<(), IdentityRegistryError> {
+        get_admin(&e)?.require_auth();
+
+        let agent_key = DataKey::Agent(agent.clone());
+        if e.storage().persistent().has(&agent_key) {
+            return Err(IdentityRegistryError::AgentAlreadyAdded);
+        }
+
+        set_persistent(&e, &agent_key, &true);
+        events::agent_added(&e, &agent);
+        Ok(())
+    }
+
+    /**
+    * Removes an agent.
+    * MUST only be done by the admin of the registry.
+    * @param _agent the address of the agent
+    */
+    pub fn remove_agent(e: Env, agent: Address) -> Result<(), IdentityRegistryError> {
+        get_admin(&e)?.require_auth();
+
+        let agent_key = DataKey::Agent(agent.clone());
+        if !e.storage().persistent().has(&agent_key) {
+            return Err(IdentityRegistryError::AgentNotAdded);
+        }
+
+        e.storage().persistent().remove(&agent_key);
+        events::agent_removed(&e, &agent);
+        Ok(())
+    }
+
+    /**
+    * Registers `wallet` with its identity and country.
+    * MUST only be done by an agent of the registry.
+    * @param _caller the agent, which must authorize the call
+    * @param _wallet the wallet of the investor, which must not be registered yet
+    * @param _identity the identity contract of the investor
+    * @param _country the ISO-3166 numeric country code of the investor
+    */
+    pub fn register_identity(e: Env, caller: Address, wallet: Address, identity: Address, country: u32) -> Result<(), IdentityRegistryError> {
+        require_agent(&e, &caller)?;
+
+        let investor_key = DataKey::Investor(wallet.clone());
+        if e.storage().persistent().has(&investor_key) {
+            return Err(IdentityRegistryError::WalletAlreadyRegistered);
+        }
+
+        set_persistent(&e, &investor_key, &Investor { identity: identity.clone(), country });
+        events::identity_registered(&e, &wallet, &identity);
+        Ok(())
+    }
+
+    /**
+    * Replaces the identity `wallet` is registered with, e.g. after the investor lost the keys of the previous one.
+    * MUST only be done by an agent of the registry.
+    * @param _caller the agent, which must authorize the call
+    * @param _wallet the registered wallet of the investor
+    * @param _identity the new identity contract of the investor
+    */
+    pub fn update_identity(e: Env, caller: Address, wallet: Address, identity: Address) -> Result<(), IdentityRegistryError> {
+        require_agent(&e, &caller)?;
+
+        let investor_key = DataKey::Investor(wallet);
+        let mut investor: Investor = get_persistent(&e, &investor_key).ok_or(IdentityRegistryError::WalletNotRegistered)?;
+        let old_identity = investor.identity;
+
+        investor.identity = identity.clone();
+        set_persistent(&e, &investor_key, &investor);
+        events::identity_updated(&e, &old_identity, &identity);
+        Ok(())
+    }
+
+    /**
+    * Updates the country `wallet` is registered with.
+    * MUST only be done by an agent of the registry.
+    * @param _caller the agent, which must authorize the call
+    * @param _wallet the registered wallet of the investor
+    * @param _country the ISO-3166 numeric country code of the investor
+    */
+    pub fn update_country(e: Env, caller: Address, wallet: Address, country: u32) -> Result<(), IdentityRegistryError> {
+        require_agent(&e, &caller)?;
+
+        let investor_key = DataKey::Investor(wallet.clone());
+        let mut investor: Investor = get_persistent(&e, &investor_key).ok_or(IdentityRegistryError::WalletNotRegistered)?;
+
+        investor.country = country;
+        set_persistent(&e, &investor_key, &investor);
+        events::country_updated(&e, &wallet, country);
+        Ok(())
+    }
+
+    /**
+    * Removes `wallet` from the registry, which is then no longer verified.
+    * MUST only be done by an agent of the registry.
+    * @param _caller the agent, which must authorize the call
+    * @param _wallet the registered wallet of the investor
+    */
+    pub fn delete_identity(e: Env, caller: Address, wallet: Address) -> Result<(), IdentityRegistryError> {
+        require_agent(&e, &caller)?;
+
+        let investor_key = DataKey::Investor(wallet.clone());
+        let investor: Investor = get_persistent(&e, &investor_key).ok_or(IdentityRegistryError::WalletNotRegistered)?;
+
+        e.storage().persistent().remove(&investor_key);
+        events::identity_removed(&e, &wallet, &investor.identity);
+        Ok(())
+    }
+
+    /**
+    * Returns TRUE if `agent` is an agent of the registry.
+    */
+    pub fn is_agent(e: Env, agent: Address) -> bool {
+        is_agent(&e, &agent)
+    }
+
+    /**
+    * Returns the claim topics registry the wallets are verified against.
+    */
+    pub fn get_claim_topics_registry(e: Env) -> Result<Address, IdentityRegistryError> {
+        e.storage().instance().get(&DataKey::ClaimTopicsRegistry).ok_or(IdentityRegistryError::NotInitialized)
+    }
+
+    /**
+    * Returns the trusted issuers registry the wallets are verified against.
+    */
+    pub fn get_trusted_issuers_registry(e: Env) -> Result<Address, IdentityRegistryError> {
+        e.storage().instance().get(&DataKey::TrustedIssuersRegistry).ok_or(IdentityRegistryError::NotInitialized)
+    }
+
+    /**
+    * Returns the admin of the registry.
+    */
+    pub fn get_admin(e: Env) -> Result<Address, IdentityRegistryError> {
+        get_admin(&e)
+    }
+}
+
+#[contractimpl]
+impl IIdentityRegistry for IdentityRegistry {
+    /**
+     * See {IIdentityRegistry-is_verified}.
+     */
+    fn is_verified(e: Env, wallet: Address) -> bool {
+        let Some(investor) = get_persistent::<Investor>(&e, &DataKey::Investor(wallet)) else {
+            return false;
+        };
+        let (Some(claim_topics_registry), Some(trusted_issuers_registry)) = (
+            e.storage().instance().get::<DataKey, Address>(&DataKey::ClaimTopicsRegistry),
+            e.storage().instance().get::<DataKey, Address>(&DataKey::TrustedIssuersRegistry),
+        ) else {
+            return false;
+        };
+
+        let trusted_issuers_client = TrustedIssuersRegistryClient::new(&e, &trusted_issuers_registry);
+        ClaimTopicsRegistryClient::new(&e, &claim_topics_registry).get_claim_topics().iter().all(|topic| {
+            let trusted_issuers = trusted_issuers_client.get_trusted_issuers_for_topic(&topic);
+            verifier::verify_topic(&e, &investor.identity, topic, &trusted_issuers) == ClaimStatus::Valid
+        })
+    }
+
+    /**
+     * See {IIdentityRegistry-contains}.
+     */
+    fn contains(e: Env, wallet: Address) -> bool {
+        e.storage().persistent().has(&DataKey::Investor(wallet))
+    }
+
+    /**
+     * See {IIdentityRegistry-get_identity}.
+     */
+    fn get_identity(e: Env, wallet: Address) -> Option<Address> {
+        get_persistent::<Investor>(&e, &DataKey::Investor(wallet)).map(|investor| investor.identity)
+    }
+
+    /**
+     * See {IIdentityRegistry-get_investor_country}.
+     */
+    fn get_investor_country(e: Env, wallet: Address) -> Option<u32> {
+        get_persistent::<Investor>(&e, &DataKey::Investor(wallet)).map(|investor| investor.country)
+    }
+}
+
+fn get_admin(e: &Env) -> Result<Address, IdentityRegistryError> {
+    e.storage().instance().get(&DataKey::Admin).ok_or(IdentityRegistryError::NotInitialized)
+}
+
+fn is_agent(e: &Env, agent: &Address) -> bool {
+    get_persistent::<bool>(e, &DataKey::Agent(agent.clone())).unwrap_or(false)
+}
+
+/**
+ * Requires the authorization of `caller` and checks that it is an agent of the registry.
+ */
+fn require_agent(e: &Env, caller: &Address) -> Result<(), IdentityRegistryError> {
+    caller.require_auth();
+    if !is_agent(e, caller) {
+        return Err(IdentityRegistryError::Unauthorized);
+    }
+    Ok(())
+}
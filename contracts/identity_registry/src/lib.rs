@@ -0,0 +1,7 @@
+#![no_std]
+#![allow(clippy::too_many_arguments)]
+
+pub mod error;
+pub mod events;
+pub mod identity_registry;
+mod test;
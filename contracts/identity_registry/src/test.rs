@@ -0,0 +1,196 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{error::IdentityRegistryError, identity_registry::{IdentityRegistry, IdentityRegistryClient}};
+use claim_issuer::claim_issuer::{ClaimIssuer, ClaimIssuerClient};
+use claim_topics_registry::claim_topics_registry::{ClaimTopicsRegistry, ClaimTopicsRegistryClient};
+use trusted_issuers_registry::trusted_issuers_registry::{TrustedIssuersRegistry, TrustedIssuersRegistryClient};
+use onchain_id::{
+    claim_topics_registry::{COUNTRY, SPECIFIC_KYC_STATUS},
+    testutils::{add_claim, last_event, register_claim_issuer, register_identity, Keypair},
+};
+use identity::identity::Identity;
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Bytes, Env, IntoVal, String, Symbol};
+
+// The registries of a permissioned token, with a claim issuer trusted for the KYC status topic.
+struct Registries<'a> {
+    identity_registry: IdentityRegistryClient<'a>,
+    claim_topics_registry: ClaimTopicsRegistryClient<'a>,
+    trusted_issuers_registry: TrustedIssuersRegistryClient<'a>,
+    claim_issuer: ClaimIssuerClient<'a>,
+    claim_key_pair: Keypair,
+    agent: Address,
+}
+
+// Registers an identity registry requiring a KYC status claim, and an agent of the registry.
+fn create_registries(env: &Env) -> Registries<'_> {
+    env.mock_all_auths();
+
+    let claim_topics_registry = ClaimTopicsRegistryClient::new(env, &env.register_contract(None, ClaimTopicsRegistry));
+    claim_topics_registry.initialize(&Address::generate(env));
+    claim_topics_registry.add_claim_topic(&SPECIFIC_KYC_STATUS, &String::from_str(env, "SPECIFIC_KYC_STATUS"), &String::from_str(env, "STRING"));
+
+    let (claim_issuer, _, claim_key_pair) = register_claim_issuer(env, ClaimIssuer);
+    let claim_issuer = ClaimIssuerClient::new(env, &claim_issuer);
+
+    let trusted_issuers_registry = TrustedIssuersRegistryClient::new(env, &env.register_contract(None, TrustedIssuersRegistry));
+    trusted_issuers_registry.initialize(&Address::generate(env));
+    trusted_issuers_registry.add_trusted_issuer(&claim_issuer.address, &vec![env, SPECIFIC_KYC_STATUS]);
+
+    let identity_registry = IdentityRegistryClient::new(env, &env.register_contract(None, IdentityRegistry));
+    identity_registry.initialize(&Address::generate(env), &claim_topics_registry.address, &trusted_issuers_registry.address);
+    let agent = Address::generate(env);
+    identity_registry.add_agent(&agent);
+
+    Registries { identity_registry, claim_topics_registry, trusted_issuers_registry, claim_issuer, claim_key_pair, agent }
+}
+
+// Adds to the identity a KYC status claim signed by the CLAIM key of the claim issuer.
+fn add_kyc_claim(env: &Env, registries: &Registries<'_>, identity: &Address, owner: &Address, valid_until: u64) -> Bytes {
+    add_claim(env, identity, owner, &registries.claim_issuer.address, &registries.claim_key_pair, SPECIFIC_KYC_STATUS, 0, valid_until)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let registries = create_registries(&env);
+    let identity_registry = &registries.identity_registry;
+
+    assert_eq!(identity_registry.get_claim_topics_registry(), registries.claim_topics_registry.address);
+    assert_eq!(identity_registry.get_trusted_issuers_registry(), registries.trusted_issuers_registry.address);
+    assert_eq!(
+        identity_registry.try_initialize(&Address::generate(&env), &Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(IdentityRegistryError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_add_and_remove_agent() {
+    let env = Env::default();
+    let registries = create_registries(&env);
+    let identity_registry = &registries.identity_registry;
+    let agent = &registries.agent;
+
+    assert!(identity_registry.is_agent(agent));
+    assert_eq!(identity_registry.try_add_agent(agent), Err(Ok(IdentityRegistryError::AgentAlreadyAdded)));
+
+    identity_registry.remove_agent(agent);
+    assert_eq!(env.auths()[0].0, identity_registry.get_admin());
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (identity_registry.address.clone(), (Symbol::new(&env, "AgentRemoved"), agent.clone()).into_val(&env), ().into_val(&env))]
+    );
+    assert!(!identity_registry.is_agent(agent));
+    assert_eq!(identity_registry.try_remove_agent(agent), Err(Ok(IdentityRegistryError::AgentNotAdded)));
+
+    // Only agents register the investors
+    assert_eq!(
+        identity_registry.try_register_identity(agent, &Address::generate(&env), &Address::generate(&env), &250),
+        Err(Ok(IdentityRegistryError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_register_identity() {
+    let env = Env::default();
+    let registries = create_registries(&env);
+    let identity_registry = &registries.identity_registry;
+    let agent = &registries.agent;
+
+    let wallet = Address::generate(&env);
+    let identity = Address::generate(&env);
+    assert!(!identity_registry.contains(&wallet));
+    identity_registry.register_identity(agent, &wallet, &identity, &250);
+    assert_eq!(env.auths()[0].0, agent.clone());
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (identity_registry.address.clone(), (Symbol::new(&env, "IdentityRegistered"), wallet.clone(), identity.clone()).into_val(&env), ().into_val(&env))]
+    );
+    assert!(identity_registry.contains(&wallet));
+    assert_eq!(identity_registry.get_identity(&wallet), Some(identity.clone()));
+    assert_eq!(identity_registry.get_investor_country(&wallet), Some(250));
+    assert_eq!(
+        identity_registry.try_register_identity(agent, &wallet, &identity, &250),
+        Err(Ok(IdentityRegistryError::WalletAlreadyRegistered))
+    );
+
+    let new_identity = Address::generate(&env);
+    identity_registry.update_identity(agent, &wallet, &new_identity);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (identity_registry.address.clone(), (Symbol::new(&env, "IdentityUpdated"), identity, new_identity.clone()).into_val(&env), ().into_val(&env))]
+    );
+    assert_eq!(identity_registry.get_identity(&wallet), Some(new_identity.clone()));
+
+    identity_registry.update_country(agent, &wallet, &276);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (identity_registry.address.clone(), (Symbol::new(&env, "CountryUpdated"), wallet.clone(), 276_u32).into_val(&env), ().into_val(&env))]
+    );
+    assert_eq!(identity_registry.get_investor_country(&wallet), Some(276));
+
+    identity_registry.delete_identity(agent, &wallet);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (identity_registry.address.clone(), (Symbol::new(&env, "IdentityRemoved"), wallet.clone(), new_identity).into_val(&env), ().into_val(&env))]
+    );
+    assert!(!identity_registry.contains(&wallet));
+    assert_eq!(identity_registry.get_investor_country(&wallet), None);
+    assert_eq!(
+        identity_registry.try_update_country(agent, &wallet, &250),
+        Err(Ok(IdentityRegistryError::WalletNotRegistered))
+    );
+    assert_eq!(
+        identity_registry.try_delete_identity(agent, &wallet),
+        Err(Ok(IdentityRegistryError::WalletNotRegistered))
+    );
+}
+
+#[test]
+fn test_is_verified() {
+    let env = Env::default();
+    let registries = create_registries(&env);
+    let identity_registry = &registries.identity_registry;
+    let (identity, owner) = register_identity(&env, Identity);
+
+    let wallet = Address::generate(&env);
+    assert!(!identity_registry.is_verified(&wallet));
+    identity_registry.register_identity(&registries.agent, &wallet, &identity, &250);
+    assert!(!identity_registry.is_verified(&wallet));
+
+    let signature = add_kyc_claim(&env, &registries, &identity, &owner, 0);
+    assert!(identity_registry.is_verified(&wallet));
+
+    // Every required topic needs a claim from an issuer trusted for it
+    registries.claim_topics_registry.add_claim_topic(&COUNTRY, &String::from_str(&env, "COUNTRY"), &String::from_str(&env, "STRING"));
+    assert!(!identity_registry.is_verified(&wallet));
+    registries.claim_topics_registry.remove_claim_topic(&COUNTRY);
+    registries.trusted_issuers_registry.update_issuer_claim_topics(&registries.claim_issuer.address, &vec![&env, COUNTRY]);
+    assert!(!identity_registry.is_verified(&wallet));
+    registries.trusted_issuers_registry.update_issuer_claim_topics(&registries.claim_issuer.address, &vec![&env, SPECIFIC_KYC_STATUS]);
+    assert!(identity_registry.is_verified(&wallet));
+
+    // A revoked claim is no longer valid
+    registries.claim_issuer.revoke_claim_by_signature(&registries.claim_issuer.address, &signature);
+    assert!(!identity_registry.is_verified(&wallet));
+}
+
+#[test]
+fn test_is_verified_with_expired_claim() {
+    let env = Env::default();
+    let registries = create_registries(&env);
+    let identity_registry = &registries.identity_registry;
+    let (identity, owner) = register_identity(&env, Identity);
+
+    let wallet = Address::generate(&env);
+    identity_registry.register_identity(&registries.agent, &wallet, &identity, &250);
+    add_kyc_claim(&env, &registries, &identity, &owner, 1_000);
+    assert!(identity_registry.is_verified(&wallet));
+
+    env.ledger().set_timestamp(1_001);
+    assert!(!identity_registry.is_verified(&wallet));
+
+    // Without any required topic, every registered wallet is verified
+    registries.claim_topics_registry.remove_claim_topic(&SPECIFIC_KYC_STATUS);
+    assert!(identity_registry.is_verified(&wallet));
+}
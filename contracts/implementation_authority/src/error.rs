@@ -0,0 +1,10 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ImplementationAuthorityError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidVersion = 3
+}
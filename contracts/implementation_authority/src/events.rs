@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use onchain_id::structs::Implementation;
+
+/**
+ * Emitted when a new implementation of the identity contract is approved.
+ * Topics: ("UpdatedImplementation", version). Data: Implementation.
+ */
+pub(crate) fn updated_implementation(e: &Env, implementation: &Implementation) {
+    e.events().publish((Symbol::new(e, "UpdatedImplementation"), implementation.version), implementation.clone());
+}
+
+/**
+ * Emitted when the ownership of the authority is transferred.
+ * Topics: ("OwnershipTransferred", previous_owner, new_owner). Data: ().
+ */
+pub(crate) fn ownership_transferred(e: &Env, previous_owner: &Address, new_owner: &Address) {
+    e.events().publish((Symbol::new(e, "OwnershipTransferred"), previous_owner.clone(), new_owner.clone()), ());
+}
@@ -0,0 +1,110 @@
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, Address, BytesN, Env};
+use onchain_id::{implementation_authority::IImplementationAuthority, storage::extend_instance, structs::Implementation};
+
+use crate::{error::ImplementationAuthorityError, events};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Owner,
+    Implementation,
+}
+
+/**
+ * Registry of the approved implementation of the identity contract, mirroring the ImplementationAuthority
+ * contract of the Solidity implementation. The identities following the authority upgrade themselves to
+ * the approved implementation with {Identity-sync_implementation}, so a fix is rolled to all of them by
+ * approving a new implementation.
+ */
+#[contract]
+pub struct ImplementationAuthority;
+
+#[contractimpl]
+impl ImplementationAuthority {
+    /**
+    * Initializes the authority with its owner and the first approved implementation.
+    * Can only be called once, right after the deployment of the authority.
+    * @param _owner address approving the implementations, which must authorize the initialization
+    * @param _wasmHash hash of the uploaded wasm of the identity contract
+    * @param _version contract version of the implementation, see {Identity-get_version}, at least 1
+    */
+    pub fn initialize(e: Env, owner: Address, wasm_hash: BytesN<32>, version: u32) -> Result<(), ImplementationAuthorityError> {
+        if e.storage().instance().has(&DataKey::Owner) {
+            return Err(ImplementationAuthorityError::AlreadyInitialized);
+        }
+        if version == 0 {
+            return Err(ImplementationAuthorityError::InvalidVersion);
+        }
+
+        owner.require_auth();
+        e.storage().instance().set(&DataKey::Owner, &owner);
+        set_implementation(&e, &Implementation { wasm_hash, version });
+        Ok(())
+    }
+
+    /**
+    * Approves a new implementation of the identity contract.
+    * MUST only be done by the owner of the authority.
+    * @param _wasmHash hash of the uploaded wasm of the identity contract
+    * @param _version contract version of the implementation, greater than the version of the current implementation
+    */
+    pub fn update_implementation(e: Env, wasm_hash: BytesN<32>, version: u32) -> Result<(), ImplementationAuthorityError> {
+        get_owner(&e)?.require_auth();
+
+        if version <= get_implementation(&e)?.version {
+            return Err(ImplementationAuthorityError::InvalidVersion);
+        }
+
+        set_implementation(&e, &Implementation { wasm_hash, version });
+        Ok(())
+    }
+
+    /**
+    * Transfers the ownership of the authority, i.e. the approval of the implementations.
+    * MUST only be done by the owner of the authority.
+    * @param _newOwner the new owner, an account or a contract
+    */
+    pub fn transfer_ownership(e: Env, new_owner: Address) -> Result<(), ImplementationAuthorityError> {
+        let owner = get_owner(&e)?;
+        owner.require_auth();
+
+        e.storage().instance().set(&DataKey::Owner, &new_owner);
+        extend_instance(&e);
+        events::ownership_transferred(&e, &owner, &new_owner);
+        Ok(())
+    }
+
+    /**
+    * Returns the owner of the authority.
+    */
+    pub fn get_owner(e: Env) -> Result<Address, ImplementationAuthorityError> {
+        get_owner(&e)
+    }
+}
+
+#[contractimpl]
+impl IImplementationAuthority for ImplementationAuthority {
+    /**
+     * See {IImplementationAuthority-getImplementation}.
+     */
+    fn get_implementation(e: Env) -> Implementation {
+        match get_implementation(&e) {
+            Ok(implementation) => implementation,
+            Err(error) => panic_with_error!(&e, error),
+        }
+    }
+}
+
+fn get_owner(e: &Env) -> Result<Address, ImplementationAuthorityError> {
+    e.storage().instance().get(&DataKey::Owner).ok_or(ImplementationAuthorityError::NotInitialized)
+}
+
+fn get_implementation(e: &Env) -> Result<Implementation, ImplementationAuthorityError> {
+    e.storage().instance().get(&DataKey::Implementation).ok_or(ImplementationAuthorityError::NotInitialized)
+}
+
+fn set_implementation(e: &Env, implementation: &Implementation) {
+    e.storage().instance().set(&DataKey::Implementation, implementation);
+    extend_instance(e);
+    events::updated_implementation(e, implementation);
+}
@@ -0,0 +1,190 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{error::ImplementationAuthorityError, implementation_authority::{ImplementationAuthority, ImplementationAuthorityClient}};
+use onchain_id::{error::OnChainIdError, key_store::address_to_key, structs::{DataKey, Implementation}};
+use identity::identity::{Identity, IdentityClient};
+use soroban_sdk::{testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, BytesN as _, Events}, vec, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+// The identity contract built with `stellar contract build`, which the approved implementations are uploads of.
+mod identity_contract {
+    use soroban_sdk::auth::Context;
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/identity.wasm");
+}
+
+// Registers an authority approving the uploaded wasm of the identity contract as version 1.
+fn create_authority(env: &Env) -> (ImplementationAuthorityClient<'_>, BytesN<32>) {
+    env.mock_all_auths();
+    // The synced identities run the wasm of the identity
+    env.budget().reset_unlimited();
+    let contract_id = env.register_contract(None, ImplementationAuthority);
+    let client = ImplementationAuthorityClient::new(env, &contract_id);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(identity_contract::WASM);
+    client.initialize(&Address::generate(env), &wasm_hash, &1);
+    (client, wasm_hash)
+}
+
+// Registers an identity following the authority.
+fn create_identity<'a>(env: &'a Env, authority: &Address) -> (IdentityClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, Identity);
+    let client = IdentityClient::new(env, &contract_id);
+
+    let owner = Address::generate(env);
+    client.initialize(&address_to_key(env, &owner), &owner);
+    client.set_implementation_authority(&owner, authority);
+    (client, owner)
+}
+
+// Returns the last event published in the test environment.
+fn last_event(env: &Env) -> Vec<(Address, Vec<Val>, Val)> {
+    let events = env.events().all();
+    events.slice(events.len() - 1..)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let (client, wasm_hash) = create_authority(&env);
+
+    assert_eq!(client.get_implementation(), Implementation { wasm_hash: wasm_hash.clone(), version: 1 });
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env), &wasm_hash, &2),
+        Err(Ok(ImplementationAuthorityError::AlreadyInitialized))
+    );
+
+    let other_client = ImplementationAuthorityClient::new(&env, &env.register_contract(None, ImplementationAuthority));
+    assert_eq!(
+        other_client.try_initialize(&Address::generate(&env), &wasm_hash, &0),
+        Err(Ok(ImplementationAuthorityError::InvalidVersion))
+    );
+}
+
+#[test]
+fn test_update_implementation() {
+    let env = Env::default();
+    let (client, wasm_hash) = create_authority(&env);
+
+    assert_eq!(
+        client.try_update_implementation(&wasm_hash, &1),
+        Err(Ok(ImplementationAuthorityError::InvalidVersion))
+    );
+
+    let new_wasm_hash = BytesN::random(&env);
+    client.update_implementation(&new_wasm_hash, &2);
+    assert_eq!(env.auths()[0].0, client.get_owner());
+    let implementation = Implementation { wasm_hash: new_wasm_hash, version: 2 };
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "UpdatedImplementation"), 2_u32).into_val(&env), implementation.into_val(&env))]
+    );
+    assert_eq!(client.get_implementation(), implementation);
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let env = Env::default();
+    let (client, _) = create_authority(&env);
+    let owner = client.get_owner();
+
+    let new_owner = Address::generate(&env);
+    client.transfer_ownership(&new_owner);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "transfer_ownership"),
+                    (new_owner.clone(),).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "OwnershipTransferred"), owner, new_owner.clone()).into_val(&env), ().into_val(&env))]
+    );
+    assert_eq!(client.get_owner(), new_owner);
+
+    // The new owner approves the implementations
+    client.update_implementation(&BytesN::random(&env), &2);
+    assert_eq!(env.auths()[0].0, new_owner);
+}
+
+#[test]
+fn test_sync_implementation() {
+    let env = Env::default();
+    let (authority, wasm_hash) = create_authority(&env);
+
+    // The identities already run the contract version 1 approved by the authority
+    let identities = [create_identity(&env, &authority.address).0, create_identity(&env, &authority.address).0];
+    for identity in identities.iter() {
+        assert_eq!(identity.get_implementation_authority(), Some(authority.address.clone()));
+        assert_eq!(identity.get_implementation_version(), 0);
+        assert!(!identity.sync_implementation());
+    }
+
+    // A new approved implementation is rolled to every identity following the authority
+    authority.update_implementation(&wasm_hash, &2);
+    for identity in identities.iter() {
+        assert!(identity.sync_implementation());
+        assert_eq!(
+            last_event(&env),
+            vec![&env, (identity.address.clone(), (Symbol::new(&env, "ImplementationSynced"), 2_u32).into_val(&env), wasm_hash.into_val(&env))]
+        );
+        assert_eq!(identity.get_implementation_version(), 2);
+
+        // Syncing again does nothing until a new implementation is approved
+        assert!(!identity.sync_implementation());
+    }
+
+    authority.update_implementation(&wasm_hash, &3);
+    for identity in identities.iter() {
+        assert!(identity.sync_implementation());
+        assert_eq!(identity.get_implementation_version(), 3);
+    }
+}
+
+#[test]
+fn test_sync_implementation_does_not_downgrade() {
+    let env = Env::default();
+    let (authority, wasm_hash) = create_authority(&env);
+    authority.update_implementation(&wasm_hash, &2);
+    let (identity, _) = create_identity(&env, &authority.address);
+
+    // An identity upgraded with upgrade to a more recent contract version keeps it
+    env.as_contract(&identity.address, || env.storage().instance().set(&DataKey::Version, &3_u32));
+    assert!(!identity.sync_implementation());
+    assert_eq!(identity.get_implementation_version(), 0);
+    assert_eq!(identity.get_version(), 3);
+}
+
+#[test]
+fn test_sync_implementation_requires_authority_and_migration() {
+    let env = Env::default();
+    let (authority, _) = create_authority(&env);
+
+    let identity = IdentityClient::new(&env, &env.register_contract(None, Identity));
+    let owner = Address::generate(&env);
+    identity.initialize(&address_to_key(&env, &owner), &owner);
+    assert_eq!(identity.try_sync_implementation(), Err(Ok(OnChainIdError::ImplementationAuthorityNotSet)));
+
+    // Only a MANAGEMENT key sets the implementation authority
+    assert_eq!(
+        identity.try_set_implementation_authority(&Address::generate(&env), &authority.address),
+        Err(Ok(OnChainIdError::Unauthorized))
+    );
+    identity.set_implementation_authority(&owner, &authority.address);
+
+    // An identity whose storage was not migrated is not upgraded
+    env.as_contract(&identity.address, || env.storage().instance().remove(&DataKey::Version));
+    assert_eq!(identity.try_sync_implementation(), Err(Ok(OnChainIdError::MigrationRequired)));
+    identity.migrate(&owner);
+    assert!(!identity.sync_implementation());
+    authority.update_implementation(&authority.get_implementation().wasm_hash, &2);
+    assert!(identity.sync_implementation());
+}
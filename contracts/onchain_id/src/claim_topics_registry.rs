@@ -0,0 +1,63 @@
+use soroban_sdk::{contractclient, Env, Vec};
+
+use crate::structs::ClaimTopic;
+
+/**
+ * Standard claim topics of the ONCHAINID catalogue, as in src/contracts/constants.js.
+ */
+pub const SPECIFIC_KYC_STATUS: u128 = 1010101;
+pub const SPECIFIC_ACCREDITATION: u128 = 1010102;
+pub const FIRST_NAME_CLEAR: u128 = 10101000042003;
+pub const LAST_NAME_CLEAR: u128 = 10101000042004;
+pub const INDIVIDUAL_INVESTOR: u128 = 10101000100000;
+pub const BASIC_IDENTITY: u128 = 10101000100001;
+pub const CONTACT_ADDRESS: u128 = 10101000100002;
+pub const SOVEREIGN_IDENTITY: u128 = 10101000100003;
+pub const POLITICALLY_EXPOSED: u128 = 10101000100004;
+pub const INSTITUTIONAL_INVESTOR: u128 = 10101000200000;
+pub const INSTITUTION_INFORMATION: u128 = 10101000200001;
+pub const INSTITUTION_DETAILS: u128 = 10101000200002;
+pub const LEGAL_IDENTIFICATION: u128 = 10101000200003;
+pub const INSTITUTIONAL_ROLE: u128 = 10101000200004;
+pub const COUNTRY: u128 = 10101000300002;
+pub const AML_STATUS: u128 = 10101000300003;
+
+/**
+ * The standard claim topics with the names and the schemes of src/contracts/claimTopics.json,
+ * which the tests of the claim topics registry check this list against.
+ */
+pub const STANDARD_CLAIM_TOPICS: [(u128, &str, &str); 16] = [
+    (FIRST_NAME_CLEAR, "FIRST_NAME_CLEAR", "URL_ENCODED"),
+    (LAST_NAME_CLEAR, "LAST_NAME_CLEAR", "URL_ENCODED"),
+    (INDIVIDUAL_INVESTOR, "INDIVIDUAL_INVESTOR", "EMPTY"),
+    (BASIC_IDENTITY, "BASIC_IDENTITY", "STRING"),
+    (CONTACT_ADDRESS, "CONTACT_ADDRESS", "STRING"),
+    (SOVEREIGN_IDENTITY, "SOVEREIGN_IDENTITY", "STRING"),
+    (POLITICALLY_EXPOSED, "POLITICALLY_EXPOSED", "STRING"),
+    (INSTITUTIONAL_INVESTOR, "INSTITUTIONAL_INVESTOR", "EMPTY"),
+    (INSTITUTION_INFORMATION, "INSTITUTION_INFORMATION", "STRING"),
+    (INSTITUTION_DETAILS, "INSTITUTION_DETAILS", "STRING"),
+    (LEGAL_IDENTIFICATION, "LEGAL_IDENTIFICATION", "STRING"),
+    (INSTITUTIONAL_ROLE, "INSTITUTIONAL_ROLE", "STRING"),
+    (COUNTRY, "COUNTRY", "STRING"),
+    (AML_STATUS, "AML_STATUS", "URL_ENCODED"),
+    (SPECIFIC_KYC_STATUS, "SPECIFIC_KYC_STATUS", "STRING"),
+    (SPECIFIC_ACCREDITATION, "SPECIFIC_ACCREDITATION", "INTEGER"),
+];
+
+/**
+ * Interface of the claim topics registry, listing the claim topics an identity must hold a valid
+ * claim of, mirroring the ClaimTopicsRegistry contract of T-REX.
+ */
+#[contractclient(name = "ClaimTopicsRegistryClient")]
+pub trait IClaimTopicsRegistry {
+    /**
+     * Returns the required claim topics, in the order they were added.
+     */
+    fn get_claim_topics(e: Env) -> Vec<u128>;
+
+    /**
+     * Returns the name and the scheme of the required claim topics, in the order they were added.
+     */
+    fn get_claim_topics_metadata(e: Env) -> Vec<ClaimTopic>;
+}
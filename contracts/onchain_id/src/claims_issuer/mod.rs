@@ -1 +1,81 @@
-pub mod traits; 
\ No newline at end of file
+pub mod traits;
+
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::{erc734::CLAIM_SIGNER_KEY, error::OnChainIdError, events, key_store, signature, storage, structs::DataKey};
+
+/**
+ * Returns the message a claim issuer signs to issue a claim on `identity`: the 32 bytes contract id
+ * of the identity, the topic as 16 big-endian bytes, the validity window as two 8 bytes big-endian
+ * timestamps, then the claim data.
+ * @param _identity the identity contract holding the claim
+ * @param _topic the claim topic
+ * @param _data the claim data
+ * @param _validFrom ledger timestamp from which the claim is valid, 0 if valid right away
+ * @param _validUntil ledger timestamp until which the claim is valid, 0 if it does not expire
+ */
+pub fn claim_message(e: &Env, identity: &Address, topic: u128, data: &Bytes, valid_from: u64, valid_until: u64) -> Bytes {
+    let mut message = Bytes::new(e);
+    message.append(&key_store::address_to_key(e, identity).into());
+    message.append(&Bytes::from_array(e, &topic.to_be_bytes()));
+    message.append(&Bytes::from_array(e, &valid_from.to_be_bytes()));
+    message.append(&Bytes::from_array(e, &valid_until.to_be_bytes()));
+    message.append(data);
+    message
+}
+
+/**
+ * Returns TRUE if the ledger timestamp is within the validity window, 0 meaning no bound.
+ */
+pub fn is_within_validity(e: &Env, valid_from: u64, valid_until: u64) -> bool {
+    let now = e.ledger().timestamp();
+    now >= valid_from && (valid_until == 0 || now <= valid_until)
+}
+
+/**
+ * Records keccak256 of the signature in the revoked claims of the current contract.
+ */
+pub fn revoke_signature(e: &Env, signature: &Bytes) -> Result<(), OnChainIdError> {
+    let signature_hash = e.crypto().keccak256(signature).to_bytes();
+    let revoked_key = DataKey::RevokedClaim(signature_hash.clone());
+    if e.storage().persistent().has(&revoked_key) {
+        return Err(OnChainIdError::ClaimAlreadyRevoked);
+    }
+
+    storage::set_persistent(e, &revoked_key, &true);
+    events::claim_revoked(e, &signature_hash, signature);
+    Ok(())
+}
+
+/**
+ * Returns TRUE if the signature was revoked by the current contract.
+ */
+pub fn is_signature_revoked(e: &Env, signature: &Bytes) -> bool {
+    let signature_hash = e.crypto().keccak256(signature).to_bytes();
+    storage::get_persistent::<bool>(e, &DataKey::RevokedClaim(signature_hash)).unwrap_or(false)
+}
+
+/**
+ * Checks a claim issued by the current contract on `identity`. The signature of the claim is
+ * the 32 bytes key of the signer followed by the signature of the claim message by this key,
+ * which must hold a CLAIM key of the current contract.
+ * An ED25519 or SECP256R1 signature not made by the signer key aborts the invocation, see signature::verify.
+ * @return claimValid Returns FALSE if the claim is outside its validity window, revoked, or ECDSA signed by another key
+ */
+pub fn verify_claim(e: &Env, identity: &Address, topic: u128, signature: &Bytes, data: &Bytes, valid_from: u64, valid_until: u64) -> Result<bool, OnChainIdError> {
+    if !is_within_validity(e, valid_from, valid_until) || is_signature_revoked(e, signature) {
+        return Ok(false);
+    }
+    if signature.len() < 32 {
+        return Err(OnChainIdError::InvalidSignature);
+    }
+
+    let signer: BytesN<32> = signature.slice(..32).try_into().map_err(|_| OnChainIdError::InvalidSignature)?;
+    if !key_store::key_has_purpose(e, &signer, CLAIM_SIGNER_KEY).unwrap_or(false) {
+        return Err(OnChainIdError::IssuerKeyNotAuthorized);
+    }
+
+    let signer_key = key_store::get_key(e, &signer)?;
+    let message = claim_message(e, identity, topic, data, valid_from, valid_until);
+    signature::verify(e, &signer_key, &message, &signature.slice(32..))
+}
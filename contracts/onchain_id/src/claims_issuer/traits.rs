@@ -1,16 +1,58 @@
-use soroban_sdk::{Bytes, BytesN, Env};
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env};
 
 use crate::error::OnChainIdError;
 
+/**
+ * Interface of the claim issuer contracts, called cross-contract by the identities to which a claim is
+ * added and by the verifiers of the claims.
+ */
+#[contractclient(name = "ClaimIssuerClient")]
 pub trait IClaimIssuer {
 
+    /**
+     * Revoke a claim previously issued, the claim is no longer considered as valid after revocation.
+     * The signature of the claim is read from the identity holding it.
+     *
+     * Triggers Event: `ClaimRevoked`
+     *
+     * MUST only be done by keys of purpose 1 (MANAGEMENT), or the issuer contract itself.
+     * `caller` must authorize the invocation.
+     * @param claimId the id of the claim
+     * @param identity the address of the identity contract holding the claim
+     * @return isRevoked true when the claim is revoked
+     */
+    fn revoke_claim(e: Env, caller: Address, claim_id: BytesN<32>, identity: Address) -> Result<bool, OnChainIdError>;
+
+    /**
+     * Revoke a claim previously issued, the claim is no longer considered as valid after revocation.
+     *
+     * Triggers Event: `ClaimRevoked`
+     *
+     * MUST only be done by keys of purpose 1 (MANAGEMENT), or the issuer contract itself.
+     * `caller` must authorize the invocation.
+     * @param signature the signature of the claim
+     */
+    fn revoke_claim_by_signature(e: Env, caller: Address, signature: Bytes) -> Result<(), OnChainIdError>;
+
+    /**
+     * Returns revocation status of a claim.
+     * @param sig the signature of the claim
+     * @return isRevoked true if the claim is revoked and false otherwise
+     */
+    fn is_claim_revoked(e: Env, sig: Bytes) -> bool;
+
     /**
      * Checks if a claim is valid.
-     * @param _identity the identity contract related to the claim
+     * The signature is the 32 bytes key of the signer, which must hold a CLAIM key of the issuer,
+     * followed by its signature of the claim message of the identity, see {ClaimIssuer-claimMessage},
+     * so that a claim signed for another identity is not valid.
+     * @param identity the identity contract holding the claim
      * @param claimTopic the claim topic of the claim
      * @param sig the signature of the claim
      * @param data the data field of the claim
-     * @return claimValid true if the claim is valid, false otherwise
+     * @param validFrom the ledger timestamp from which the claim is valid, 0 if not bounded
+     * @param validUntil the ledger timestamp until which the claim is valid, 0 if not bounded
+     * @return claimValid true if the claim is valid, false otherwise, e.g. outside its validity window or revoked
      */
-    fn is_claim_valid(e: Env, identity: BytesN<32>, issuer: BytesN<32>, topic: u32, sig: BytesN<64>, data: Bytes) -> Result<bool, OnChainIdError>;
-}
\ No newline at end of file
+    fn is_claim_valid(e: Env, identity: Address, topic: u128, sig: Bytes, data: Bytes, valid_from: u64, valid_until: u64) -> Result<bool, OnChainIdError>;
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/**
+ * Interface of the compliance of a permissioned token, mirroring the ModularCompliance contract of ERC-3643.
+ * The token checks its transfers and mints with the compliance, then reports them through the hooks so the
+ * compliance keeps track of the holdings. The hooks MUST only be called by the token bound to the compliance.
+ */
+#[contractclient(name = "ComplianceClient")]
+pub trait ICompliance {
+    /**
+     * Returns TRUE if the compliance allows `from` to transfer `amount` tokens to `to`.
+     */
+    fn can_transfer(e: Env, from: Address, to: Address, amount: i128) -> bool;
+
+    /**
+     * Returns TRUE if the compliance allows `amount` tokens to be minted to `to`.
+     */
+    fn can_create(e: Env, to: Address, amount: i128) -> bool;
+
+    /**
+     * Records the transfer of `amount` tokens from `from` to `to`.
+     */
+    fn transferred(e: Env, from: Address, to: Address, amount: i128);
+
+    /**
+     * Records the mint of `amount` tokens to `to`.
+     */
+    fn created(e: Env, to: Address, amount: i128);
+
+    /**
+     * Records the burn of `amount` tokens of `from`.
+     */
+    fn destroyed(e: Env, from: Address, amount: i128);
+}
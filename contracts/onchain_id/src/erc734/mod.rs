@@ -1 +1,16 @@
-pub mod traits; 
\ No newline at end of file
+pub mod traits;
+
+/// Key purposes defined by the ERC-734 standard.
+pub const MANAGEMENT_KEY: u32 = 1;
+pub const ACTION_KEY: u32 = 2;
+pub const CLAIM_SIGNER_KEY: u32 = 3;
+pub const ENCRYPTION_KEY: u32 = 4;
+
+/// Key types. ERC-734 proposes 1 = ECDSA and 2 = RSA, ed25519 being the key type of Stellar accounts
+/// and secp256r1 (P-256) the one of passkeys. Keys registered with type 1 before ED25519_KEY_TYPE existed
+/// are ed25519 keys: the identities holding them cannot be upgraded, and are redeployed with these keys
+/// added again with ED25519_KEY_TYPE.
+pub const ECDSA_KEY_TYPE: u32 = 1;
+pub const RSA_KEY_TYPE: u32 = 2;
+pub const ED25519_KEY_TYPE: u32 = 3;
+pub const SECP256R1_KEY_TYPE: u32 = 4;
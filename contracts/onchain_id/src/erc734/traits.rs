@@ -1,7 +1,8 @@
-use soroban_sdk::{BytesN, Env, Vec};
+use soroban_sdk::{contractclient, Address, BytesN, Env, Symbol, Val, Vec};
 
 use crate::error::OnChainIdError;
 
+#[contractclient(name = "ERC734Client")]
 pub trait IERC734 {
     /**
      * Adds a _key to the identity. The _purpose specifies the purpose of the key.
@@ -10,8 +11,33 @@ pub trait IERC734 {
      *
      * Specification: MUST only be done by keys of purpose 1, or the identity
      * itself. If it's the identity itself, the approval process will determine its approval.
+     * `caller` must authorize the invocation.
      */
-    fn add_key(e: Env, key: BytesN<32>, purpose: u32, key_type: u32) -> Result<bool, OnChainIdError>;
+    fn add_key(e: Env, caller: Address, key: BytesN<32>, purpose: u32, key_type: u32) -> Result<bool, OnChainIdError>;
+
+    /**
+     * Approves an execution.
+     *
+     * Triggers Event: `Approved`
+     * Triggers on execution successful Event: `Executed`
+     * Triggers on execution failure Event: `ExecutionFailed`
+     *
+     * Specification: executions to the identity itself MUST be approved by keys of purpose 1,
+     * executions to other contracts by keys of purpose 2. `caller` must authorize the invocation.
+     */
+    fn approve(e: Env, caller: Address, execution_id: u64, approve: bool) -> Result<bool, OnChainIdError>;
+
+    /**
+     * Passes an execution instruction to an ERC734 identity.
+     * How the execution is handled is up to the identity implementation:
+     * An execution COULD be requested and require `approve` to be called with one or more keys of purpose 1 or 2 to
+     * approve this execution.
+     * Execute COULD be used as the only accessor for `add_key` and `remove_key`.
+     *
+     * Triggers Event: ExecutionRequested
+     * Triggers on direct execution Event: Executed
+     */
+    fn execute(e: Env, caller: Address, to: Address, fn_name: Symbol, args: Vec<Val>) -> Result<u64, OnChainIdError>;
 
     /**
      * Removes _purpose for _key from the identity.
@@ -20,8 +46,9 @@ pub trait IERC734 {
      *
      * Specification: MUST only be done by keys of purpose 1, or the identity itself.
      * If it's the identity itself, the approval process will determine its approval.
+     * `caller` must authorize the invocation.
      */
-    fn remove_key(e: Env, key: BytesN<32>, purpose: u32) -> Result<bool, OnChainIdError>;
+    fn remove_key(e: Env, caller: Address, key: BytesN<32>, purpose: u32) -> Result<bool, OnChainIdError>;
 
     /**
      * Returns the full key data, if present in the identity.
@@ -1,4 +1,4 @@
-use soroban_sdk::{Bytes, BytesN, Env, String, Vec};
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String, Vec};
 
 use crate::error::OnChainIdError;
 
@@ -13,6 +13,7 @@ use crate::error::OnChainIdError;
      * `keccak256(address identityHolder_address, uint256 topic, bytes data)`.
      * Claim IDs are generated using `keccak256(address issuer_address + uint256 topic)`.
      */
+#[contractclient(name = "ERC735Client")]
 pub trait IERC735 {
     /**
      * Add or update a claim.
@@ -24,15 +25,20 @@ pub trait IERC735 {
      * _signature is a signed message of the following structure:
      * `keccak256(address identityHolder_address, uint256 topic, bytes data)`.
      * Claim IDs are generated using `keccak256(address issuer_address + uint256 topic)`.
+     *
+     * MUST only be done by keys of purpose 3 (CLAIM), or the identity itself.
+     * `caller` must authorize the invocation.
+     * `valid_from` and `valid_until` bound the validity of the claim, 0 meaning no bound,
+     * and are covered by the signature.
      */
-    fn add_claim(e: Env, topic: u32, scheme: u32, issuer: BytesN<32>, signature: BytesN<64>, data: Bytes, uri: String) -> BytesN<32>;
+    fn add_claim(e: Env, caller: Address, topic: u128, scheme: u128, issuer: Address, signature: Bytes, data: Bytes, uri: String, valid_from: u64, valid_until: u64) -> Result<BytesN<32>, OnChainIdError>;
 
     /**
      * Get a claim by its ID.
      *
      * Claim IDs are generated using `keccak256(abi.encode(address issuer_address, uint256 topic))`.
      */
-    fn get_claim(e: Env, claim_id: BytesN<32>) -> Result<(u32, u32, BytesN<32>, BytesN<64>, Bytes, String), OnChainIdError>;
+    fn get_claim(e: Env, claim_id: BytesN<32>) -> Result<(u128, u128, Address, Bytes, Bytes, String), OnChainIdError>;
 
     /**
      * Removes a claim.
@@ -40,11 +46,14 @@ pub trait IERC735 {
      * Triggers Event: `ClaimRemoved`
      *
      * Claim IDs are generated using `keccak256(address issuer_address, uint256 topic)`.
+     *
+     * MUST only be done by the claim issuer, or the claim holder itself.
+     * `caller` must authorize the invocation.
      */
-    fn remove_claim(e: Env, claim_id: BytesN<32>) -> Result<bool, OnChainIdError>;
+    fn remove_claim(e: Env, caller: Address, claim_id: BytesN<32>) -> Result<bool, OnChainIdError>;
 
     /**
      * Returns an array of claim IDs by topic.
      */
-    fn get_claim_ids_by_topic(e: Env, topic: u32) -> Result<Vec<BytesN<32>>, OnChainIdError>;
+    fn get_claim_ids_by_topic(e: Env, topic: u128) -> Result<Vec<BytesN<32>>, OnChainIdError>;
 }
@@ -9,5 +9,21 @@ pub enum OnChainIdError {
     KeyDoesntHavePurpose = 3,
     NoClaimFound = 4,
     NoClaimTopicFound = 5,
-    IssuerKeyNotAuthorized = 6
-}
\ No newline at end of file
+    IssuerKeyNotAuthorized = 6,
+    Unauthorized = 7,
+    InvalidClaim = 8,
+    AlreadyInitialized = 9,
+    ExecutionNotFound = 10,
+    ExecutionAlreadyExecuted = 11,
+    UnsupportedExecution = 12,
+    InvalidTtlConfig = 13,
+    InvalidSignature = 14,
+    UnsupportedKeyType = 15,
+    PublicKeyRequired = 16,
+    ClaimExpired = 17,
+    ClaimAlreadyRevoked = 18,
+    MigrationRequired = 19,
+    ImplementationAuthorityNotSet = 20,
+    OwnerKeyMismatch = 21,
+    ClaimNotYetValid = 22
+}
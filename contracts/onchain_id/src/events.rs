@@ -0,0 +1,146 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::structs::{Claim, Execution};
+
+/**
+ * Data of the `KeyAdded` and `KeyRemoved` events.
+ * key: the public key which gained or lost the purpose
+ * purpose: the purpose added to or removed from the key
+ * key_type: the type of the key, e.g. 1 = ECDSA, 2 = RSA, 3 = ED25519
+ */
+#[derive(Clone)]
+#[contracttype]
+pub struct KeyEvent {
+    pub key: BytesN<32>,
+    pub purpose: u32,
+    pub key_type: u32,
+}
+
+/**
+ * Emitted when a purpose is added to a key.
+ * Topics: ("KeyAdded", key, purpose). Data: KeyEvent.
+ */
+pub fn key_added(e: &Env, key: &BytesN<32>, purpose: u32, key_type: u32) {
+    publish_key_event(e, "KeyAdded", key, purpose, key_type);
+}
+
+/**
+ * Emitted when a purpose is removed from a key.
+ * Topics: ("KeyRemoved", key, purpose). Data: KeyEvent.
+ */
+pub fn key_removed(e: &Env, key: &BytesN<32>, purpose: u32, key_type: u32) {
+    publish_key_event(e, "KeyRemoved", key, purpose, key_type);
+}
+
+fn publish_key_event(e: &Env, name: &str, key: &BytesN<32>, purpose: u32, key_type: u32) {
+    let event = KeyEvent {
+        key: key.clone(),
+        purpose,
+        key_type,
+    };
+    e.events().publish((Symbol::new(e, name), key.clone(), purpose), event);
+}
+
+/**
+ * Emitted when a claim is added to the identity.
+ * Topics: ("ClaimAdded", claim_id, topic). Data: Claim.
+ */
+pub fn claim_added(e: &Env, claim_id: &BytesN<32>, claim: &Claim) {
+    publish_claim_event(e, "ClaimAdded", claim_id, claim);
+}
+
+/**
+ * Emitted when an existing claim is updated.
+ * Topics: ("ClaimChanged", claim_id, topic). Data: Claim.
+ */
+pub fn claim_changed(e: &Env, claim_id: &BytesN<32>, claim: &Claim) {
+    publish_claim_event(e, "ClaimChanged", claim_id, claim);
+}
+
+/**
+ * Emitted when a claim is removed from the identity.
+ * Topics: ("ClaimRemoved", claim_id, topic). Data: Claim.
+ */
+pub fn claim_removed(e: &Env, claim_id: &BytesN<32>, claim: &Claim) {
+    publish_claim_event(e, "ClaimRemoved", claim_id, claim);
+}
+
+fn publish_claim_event(e: &Env, name: &str, claim_id: &BytesN<32>, claim: &Claim) {
+    e.events().publish((Symbol::new(e, name), claim_id.clone(), claim.topic), claim.clone());
+}
+
+/**
+ * Emitted when a claim issuer revokes a claim it issued.
+ * Topics: ("ClaimRevoked", keccak256(signature)). Data: the signature of the claim.
+ */
+pub fn claim_revoked(e: &Env, signature_hash: &BytesN<32>, signature: &Bytes) {
+    e.events().publish((Symbol::new(e, "ClaimRevoked"), signature_hash.clone()), signature.clone());
+}
+
+/**
+ * Emitted when an execution is requested.
+ * Topics: ("ExecutionRequested", execution_id, to). Data: Execution.
+ */
+pub fn execution_requested(e: &Env, execution_id: u64, execution: &Execution) {
+    publish_execution_event(e, "ExecutionRequested", execution_id, execution);
+}
+
+/**
+ * Emitted when an execution is approved or rejected.
+ * Topics: ("Approved", execution_id). Data: the approval status.
+ */
+pub fn approved(e: &Env, execution_id: u64, approve: bool) {
+    e.events().publish((Symbol::new(e, "Approved"), execution_id), approve);
+}
+
+/**
+ * Emitted when an approved execution succeeded.
+ * Topics: ("Executed", execution_id, to). Data: Execution.
+ */
+pub fn executed(e: &Env, execution_id: u64, execution: &Execution) {
+    publish_execution_event(e, "Executed", execution_id, execution);
+}
+
+/**
+ * Emitted when an approved execution failed.
+ * Topics: ("ExecutionFailed", execution_id, to). Data: Execution.
+ */
+pub fn execution_failed(e: &Env, execution_id: u64, execution: &Execution) {
+    publish_execution_event(e, "ExecutionFailed", execution_id, execution);
+}
+
+fn publish_execution_event(e: &Env, name: &str, execution_id: u64, execution: &Execution) {
+    e.events().publish((Symbol::new(e, name), execution_id, execution.to.clone()), execution.clone());
+}
+
+/**
+ * Emitted when the identity is upgraded to a new wasm, whose version is only known once it runs.
+ * Topics: ("Upgraded",). Data: the hash of the new wasm.
+ */
+pub fn upgraded(e: &Env, new_wasm_hash: &BytesN<32>) {
+    e.events().publish((Symbol::new(e, "Upgraded"),), new_wasm_hash.clone());
+}
+
+/**
+ * Emitted when the storage of the identity is migrated to the layout of the current contract version.
+ * Topics: ("Migrated", from_version). Data: the current contract version.
+ */
+pub fn migrated(e: &Env, from_version: u32, to_version: u32) {
+    e.events().publish((Symbol::new(e, "Migrated"), from_version), to_version);
+}
+
+/**
+ * Emitted when the implementation authority followed by the identity is set.
+ * Topics: ("ImplementationAuthoritySet", authority). Data: ().
+ */
+pub fn implementation_authority_set(e: &Env, authority: &Address) {
+    e.events().publish((Symbol::new(e, "ImplementationAuthoritySet"), authority.clone()), ());
+}
+
+/**
+ * Emitted when the identity is upgraded to the implementation approved by its implementation authority.
+ * Topics: ("ImplementationSynced", version). Data: the hash of the new wasm.
+ */
+pub fn implementation_synced(e: &Env, version: u32, wasm_hash: &BytesN<32>) {
+    e.events().publish((Symbol::new(e, "ImplementationSynced"), version), wasm_hash.clone());
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/**
+ * Interface of the identity registry, linking the wallets of the investors to their identity and their
+ * country, mirroring the IdentityRegistry contract of ERC-3643. Called cross-contract by the permissioned
+ * tokens and their compliance to check the holders.
+ */
+#[contractclient(name = "IdentityRegistryClient")]
+pub trait IIdentityRegistry {
+    /**
+     * Returns TRUE if `wallet` is registered with an identity holding, for each claim topic of the claim
+     * topics registry, a valid claim issued by an issuer trusted for the topic by the trusted issuers registry.
+     */
+    fn is_verified(e: Env, wallet: Address) -> bool;
+
+    /**
+     * Returns TRUE if `wallet` is registered in the registry.
+     */
+    fn contains(e: Env, wallet: Address) -> bool;
+
+    /**
+     * Returns the identity `wallet` is registered with, if any.
+     */
+    fn get_identity(e: Env, wallet: Address) -> Option<Address>;
+
+    /**
+     * Returns the ISO-3166 numeric country code `wallet` is registered with, if any.
+     */
+    fn get_investor_country(e: Env, wallet: Address) -> Option<u32>;
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{contractclient, Env};
+
+use crate::structs::Implementation;
+
+/**
+ * Interface of the implementation authority, which decides which implementation of the identity
+ * contract the identities following it run. Called cross-contract by {Identity-sync_implementation}.
+ */
+#[contractclient(name = "ImplementationAuthorityClient")]
+pub trait IImplementationAuthority {
+    /**
+     * Returns the wasm hash and the version of the approved implementation of the identity contract.
+     */
+    fn get_implementation(e: Env) -> Implementation;
+}
@@ -0,0 +1,166 @@
+/*!
+ * ERC-734 key store shared by the contracts acting as an identity: the identity of a claim holder
+ * and the identity of a claim issuer. Keys are stored in the storage of the current contract.
+ */
+
+use soroban_sdk::{vec, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{erc734::{ED25519_KEY_TYPE, MANAGEMENT_KEY, SECP256R1_KEY_TYPE}, error::OnChainIdError, events, storage, structs::{DataKey, Key}};
+
+/**
+ * Returns the key identifying an address: the ed25519 public key of a Stellar account
+ * or the id of a contract. Both are the trailing 32 bytes of the address XDR.
+ */
+pub fn address_to_key(e: &Env, address: &Address) -> BytesN<32> {
+    let address_xdr = address.clone().to_xdr(e);
+    address_xdr.slice(address_xdr.len() - 32..).try_into().unwrap()
+}
+
+/**
+ * Returns TRUE if the address is a Stellar account rather than a contract, from the ScAddress
+ * discriminant of its XDR.
+ */
+pub fn is_account(e: &Env, address: &Address) -> bool {
+    address.clone().to_xdr(e).get_unchecked(7) == 0
+}
+
+/**
+ * Registers the owner key with purpose 1 = MANAGEMENT, after checking the authorization of the owner.
+ * Can only be done once. An account owner can only register its own public key, so that nobody
+ * initializing the contract before its owner can register a key of the owner's account. A contract
+ * owner, e.g. the IdFactory deploying and initializing an identity in the same invocation, can
+ * register the key of another address.
+ * @param _ownerKey ed25519 public key of the owner
+ * @param _ownerAddress address of the owner, which must authorize the initialization
+ */
+pub fn initialize(e: &Env, owner_key: BytesN<32>, owner_address: &Address) -> Result<(), OnChainIdError> {
+    if e.storage().instance().has(&DataKey::Owner) {
+        return Err(OnChainIdError::AlreadyInitialized);
+    }
+    if is_account(e, owner_address) && owner_key != address_to_key(e, owner_address) {
+        return Err(OnChainIdError::OwnerKeyMismatch);
+    }
+
+    owner_address.require_auth();
+    e.storage().instance().set(&DataKey::Owner, owner_address);
+    store_key(e, owner_key, MANAGEMENT_KEY, ED25519_KEY_TYPE, None)
+}
+
+/**
+ * Requires the authorization of `caller` and checks that it is either the current contract
+ * or an address whose key holds `purpose`.
+ */
+pub fn require_key_purpose(e: &Env, caller: &Address, purpose: u32) -> Result<(), OnChainIdError> {
+    caller.require_auth();
+    if *caller == e.current_contract_address() {
+        return Ok(());
+    }
+
+    match key_has_purpose(e, &address_to_key(e, caller), purpose) {
+        Ok(true) => Ok(()),
+        _ => Err(OnChainIdError::Unauthorized),
+    }
+}
+
+/**
+ * Registers `purpose` for `key`, creating the key if it is not present yet.
+ * SECP256R1 keys are created with their full public key, needed to verify their signatures.
+ */
+pub fn store_key(e: &Env, key: BytesN<32>, purpose: u32, key_type: u32, public_key: Option<BytesN<65>>) -> Result<(), OnChainIdError> {
+    let map_key = DataKey::Key(key.clone());
+    if let Some(mut retrieved_key) = storage::get_persistent::<Key>(e, &map_key) {
+        let retrieved_purposes: Vec<u32> = retrieved_key.purposes.clone();
+
+        for retrieved_purpose in retrieved_purposes {
+            if purpose == retrieved_purpose {
+                return Err(OnChainIdError::KeyAlreadyHasPurpose);
+            }
+        }
+
+        retrieved_key.purposes.push_back(purpose);
+        storage::set_persistent(e, &map_key, &retrieved_key);
+        events::key_added(e, &key, purpose, retrieved_key.key_type);
+    } else {
+        if key_type == SECP256R1_KEY_TYPE && public_key.is_none() {
+            return Err(OnChainIdError::PublicKeyRequired);
+        }
+
+        let new_purposes: Vec<u32> = vec![e, purpose];
+        let new_key: Key = Key {
+            purposes: new_purposes,
+            key_type,
+            key: key.clone(),
+            public_key: public_key.map(Bytes::from),
+        };
+        storage::set_persistent(e, &map_key, &new_key);
+        events::key_added(e, &key, purpose, key_type);
+    }
+
+    let purpose_key = DataKey::Purpose(purpose);
+    if let Some(mut retrieved_purpose_keys) = storage::get_persistent::<Vec<BytesN<32>>>(e, &purpose_key) {
+        retrieved_purpose_keys.push_back(key);
+        storage::set_persistent(e, &purpose_key, &retrieved_purpose_keys);
+    } else {
+        let retrieved_purpose_keys = vec![e, key];
+        storage::set_persistent(e, &purpose_key, &retrieved_purpose_keys);
+        storage::add_to_index(e, &DataKey::Purposes, purpose);
+    }
+
+    Ok(())
+}
+
+/**
+ * Removes `purpose` from `key`, deleting the key once it has no purpose left.
+ * Fails without changing the storage if the key, or the list of the keys having `purpose`, does not
+ * hold the purpose.
+ */
+pub fn remove_key(e: &Env, key: BytesN<32>, purpose: u32) -> Result<(), OnChainIdError> {
+    let map_key = DataKey::Key(key.clone());
+    let mut retrieved_key = storage::get_persistent::<Key>(e, &map_key).ok_or(OnChainIdError::KeyNotRegistered)?;
+    let purpose_index = retrieved_key.purposes.first_index_of(purpose).ok_or(OnChainIdError::KeyDoesntHavePurpose)?;
+
+    let map_purpose = DataKey::Purpose(purpose);
+    let mut retrieved_keys = storage::get_persistent::<Vec<BytesN<32>>>(e, &map_purpose).unwrap_or(vec![e]);
+    let key_index = retrieved_keys.first_index_of(&key).ok_or(OnChainIdError::KeyDoesntHavePurpose)?;
+
+    retrieved_key.purposes.remove(purpose_index);
+    if retrieved_key.purposes.is_empty() {
+        e.storage().persistent().remove(&map_key);
+    } else {
+        storage::set_persistent(e, &map_key, &retrieved_key);
+    }
+
+    retrieved_keys.remove(key_index);
+    storage::set_persistent(e, &map_purpose, &retrieved_keys);
+
+    events::key_removed(e, &key, purpose, retrieved_key.key_type);
+    Ok(())
+}
+
+/**
+ * Returns the full data of a key.
+ */
+pub fn get_key(e: &Env, key: &BytesN<32>) -> Result<Key, OnChainIdError> {
+    storage::get_persistent::<Key>(e, &DataKey::Key(key.clone())).ok_or(OnChainIdError::KeyNotRegistered)
+}
+
+/**
+ * Returns the keys having `purpose`.
+ */
+pub fn get_keys_by_purpose(e: &Env, purpose: u32) -> Vec<BytesN<32>> {
+    storage::get_persistent::<Vec<BytesN<32>>>(e, &DataKey::Purpose(purpose)).unwrap_or(vec![e])
+}
+
+/**
+ * Checks if a key has a specific purpose. A MANAGEMENT key is considered to hold every purpose.
+ */
+pub fn key_has_purpose(e: &Env, key: &BytesN<32>, purpose: u32) -> Result<bool, OnChainIdError> {
+    let retrieved_key = get_key(e, key)?;
+    for retrieved_purpose in retrieved_key.purposes {
+        if retrieved_purpose == MANAGEMENT_KEY || purpose == retrieved_purpose {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
@@ -1,9 +1,21 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
-mod identity;
-mod erc734;
-mod erc735;
-mod structs;
-mod claims_issuer;
-mod error;
-mod test;
+pub mod erc734;
+pub mod erc735;
+pub mod structs;
+pub mod claims_issuer;
+pub mod implementation_authority;
+pub mod claim_topics_registry;
+pub mod trusted_issuers_registry;
+pub mod identity_registry;
+pub mod verifier;
+pub mod compliance;
+pub mod error;
+pub mod events;
+pub mod key_store;
+pub mod signature;
+pub mod storage;
+// Helpers shared by the tests of the contracts
+#[cfg(feature = "testutils")]
+pub mod testutils;
@@ -0,0 +1,71 @@
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::{erc734::{ECDSA_KEY_TYPE, ED25519_KEY_TYPE, SECP256R1_KEY_TYPE}, error::OnChainIdError, structs::Key};
+
+/**
+ * Returns the ERC-734 key of a secp256k1 public key, i.e. keccak256(abi.encode(address)) where
+ * address is the Ethereum address of the key: the last 20 bytes of keccak256 of the public key.
+ * @param _publicKey the uncompressed SEC-1 encoded public key
+ */
+pub fn ecdsa_key_hash(e: &Env, public_key: &BytesN<65>) -> BytesN<32> {
+    let public_key: Bytes = public_key.into();
+    let address_hash: Bytes = e.crypto().keccak256(&public_key.slice(1..)).to_bytes().into();
+
+    let mut encoded_address = Bytes::from_array(e, &[0; 12]);
+    encoded_address.append(&address_hash.slice(12..));
+    e.crypto().keccak256(&encoded_address).to_bytes()
+}
+
+/**
+ * Returns the ERC-734 key of a secp256r1 public key, i.e. keccak256 of the public key.
+ * @param _publicKey the uncompressed SEC-1 encoded public key
+ */
+pub fn secp256r1_key_hash(e: &Env, public_key: &BytesN<65>) -> BytesN<32> {
+    e.crypto().keccak256(&public_key.into()).to_bytes()
+}
+
+/**
+ * Verifies that `signature` was produced over `message` by the private key of `key`, according to its type.
+ * ED25519 signatures are the 64 bytes signature of the message.
+ * ECDSA signatures are the 65 bytes r || s || v signature of keccak256(message), v being the recovery id
+ * either as 0/1 or as 27/28.
+ * SECP256R1 signatures are the 64 bytes r || s signature of sha256(message), with a low s.
+ * ED25519 and SECP256R1 signatures not made by the key abort the invocation, as the host verification traps.
+ * ECDSA signatures made by another key recover another address.
+ * @return valid Returns TRUE if the signature is valid, FALSE if an ECDSA signature was made by another key
+ */
+pub fn verify(e: &Env, key: &Key, message: &Bytes, signature: &Bytes) -> Result<bool, OnChainIdError> {
+    match key.key_type {
+        ED25519_KEY_TYPE => {
+            let signature: BytesN<64> = signature.try_into().map_err(|_| OnChainIdError::InvalidSignature)?;
+            // Traps if the signature is invalid
+            e.crypto().ed25519_verify(&key.key, message, &signature);
+            Ok(true)
+        }
+        ECDSA_KEY_TYPE => {
+            if signature.len() != 65 {
+                return Err(OnChainIdError::InvalidSignature);
+            }
+            let rs: BytesN<64> = signature.slice(..64).try_into().map_err(|_| OnChainIdError::InvalidSignature)?;
+            let recovery_id = match signature.get_unchecked(64) {
+                v @ 0..=1 => v as u32,
+                v @ 27..=28 => (v - 27) as u32,
+                _ => return Err(OnChainIdError::InvalidSignature),
+            };
+
+            let digest = e.crypto().keccak256(message);
+            let public_key = e.crypto().secp256k1_recover(&digest, &rs, recovery_id);
+            Ok(ecdsa_key_hash(e, &public_key) == key.key)
+        }
+        SECP256R1_KEY_TYPE => {
+            let public_key: BytesN<65> = key.public_key.clone()
+                .and_then(|public_key| public_key.try_into().ok())
+                .ok_or(OnChainIdError::PublicKeyRequired)?;
+            let signature: BytesN<64> = signature.try_into().map_err(|_| OnChainIdError::InvalidSignature)?;
+            // Traps if the signature is invalid
+            e.crypto().secp256r1_verify(&public_key, &e.crypto().sha256(message), &signature);
+            Ok(true)
+        }
+        _ => Err(OnChainIdError::UnsupportedKeyType),
+    }
+}
@@ -0,0 +1,82 @@
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val, Vec};
+
+use crate::structs::{DataKey, TtlConfig};
+
+pub const DAY_IN_LEDGERS: u32 = 17280;
+
+/**
+ * Default TTL configuration: entries living less than 30 more days are extended to 120 days.
+ */
+pub const DEFAULT_TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+
+/**
+ * Returns the TTL configuration stored in the instance, or the default one.
+ * Only the identity stores a configuration, the other contracts use the default one.
+ */
+pub fn get_ttl_config(e: &Env) -> TtlConfig {
+    e.storage().instance().get(&DataKey::TtlConfig).unwrap_or(TtlConfig {
+        threshold: DEFAULT_TTL_THRESHOLD,
+        extend_to: DEFAULT_TTL_EXTEND_TO,
+    })
+}
+
+pub fn set_ttl_config(e: &Env, config: &TtlConfig) {
+    e.storage().instance().set(&DataKey::TtlConfig, config);
+}
+
+/**
+ * Extends the TTL of the instance, which holds the configuration and the indexes of the contract.
+ */
+pub fn extend_instance(e: &Env) {
+    let config = get_ttl_config(e);
+    e.storage().instance().extend_ttl(config.threshold, config.extend_to);
+}
+
+/**
+ * Extends the TTL of a persistent entry, if present, along with the one of the instance.
+ */
+pub fn extend_persistent(e: &Env, key: &impl IntoVal<Env, Val>) {
+    let config = get_ttl_config(e);
+    if e.storage().persistent().has(key) {
+        e.storage().persistent().extend_ttl(key, config.threshold, config.extend_to);
+    }
+    e.storage().instance().extend_ttl(config.threshold, config.extend_to);
+}
+
+/**
+ * Reads a persistent entry and extends its TTL.
+ */
+pub fn get_persistent<V: TryFromVal<Env, Val>>(e: &Env, key: &impl IntoVal<Env, Val>) -> Option<V> {
+    let value = e.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent(e, key);
+    }
+    value
+}
+
+/**
+ * Writes a persistent entry and extends its TTL.
+ */
+pub fn set_persistent<V: IntoVal<Env, Val>>(e: &Env, key: &impl IntoVal<Env, Val>, value: &V) {
+    e.storage().persistent().set(key, value);
+    extend_persistent(e, key);
+}
+
+/**
+ * Returns the values of an index kept in the instance, e.g. the purposes or the claim topics in use.
+ */
+pub fn get_index<V: IntoVal<Env, Val> + TryFromVal<Env, Val>>(e: &Env, index: &DataKey) -> Vec<V> {
+    e.storage().instance().get(index).unwrap_or(Vec::new(e))
+}
+
+/**
+ * Adds a value to an index kept in the instance, if not present yet.
+ */
+pub fn add_to_index<V: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone>(e: &Env, index: &DataKey, value: V) {
+    let mut values: Vec<V> = get_index(e, index);
+    if !values.contains(&value) {
+        values.push_back(value);
+        e.storage().instance().set(index, &values);
+    }
+}
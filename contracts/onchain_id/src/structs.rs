@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec, U256};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Symbol, Val, Vec};
 
 /**
     *  Definition of the structure of a Key.
@@ -7,8 +7,9 @@ use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec, U256};
     *  The structure should be as follows:
     *  key: A public key owned by this identity
     *  purposes: uint256[] Array of the key purposes, like 1 = MANAGEMENT, 2 = EXECUTION
-    *  keyType: The type of key used, which would be a uint256 for different key types. e.g. 1 = ECDSA, 2 = RSA, etc.
-    *  key: bytes32 The public key. // Its the Keccak256 hash of the key
+    *  keyType: The type of key used, which would be a uint256 for different key types. e.g. 1 = ECDSA, 2 = RSA, 3 = ED25519, etc.
+    *  key: bytes32 The public key. // Its the raw public key of ED25519 keys and the Keccak256 hash of the key otherwise
+    *  public_key: The full SEC-1 encoded public key of SECP256R1 keys, which cannot be recovered from a signature
     */
 
     #[derive(Clone)]
@@ -17,6 +18,7 @@ use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec, U256};
         pub purposes: Vec<u32>,
         pub key_type: u32,
         pub key: BytesN<32>,
+        pub public_key: Option<Bytes>,
     }
 
     /**
@@ -24,8 +26,8 @@ use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec, U256};
     *
     *  Specification: Executions are requests for transactions to be issued by the ONCHAINID
     *  to: address of contract to interact with, can be address(this)
-    *  value: ETH to transfer with the transaction
-    *  data: payload of the transaction to execute
+    *  fn_name: name of the contract function to invoke
+    *  args: arguments of the contract function to invoke
     *  approved: approval status of the Execution
     *  executed: execution status of the Execution (set as false when the Execution is created
     *  and updated to true when the Execution is processed)
@@ -35,8 +37,8 @@ use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec, U256};
     #[contracttype]
 pub struct Execution {
         pub to: Address,
-        pub value: U256,
-        pub data: Bytes,
+        pub fn_name: Symbol,
+        pub args: Vec<Val>,
         pub approved: bool,
         pub executed: bool,
     }
@@ -53,24 +55,130 @@ pub struct Execution {
     *  different schemes. E.g. could 3 mean contract verification, where the data will be call data, and the issuer a
     *  contract address to call (ToBeDefined). Those can also mean different key types e.g. 1 = ECDSA, 2 = RSA, etc.
     *  (ToBeDefined)
-    *  issuer: The issuers identity contract address, or the address used to sign the above signature. If an
-    *  identity contract, it should hold the key with which the above message was signed, if the key is not present
-    *  anymore, the claim SHOULD be treated as invalid. The issuer can also be a contract address itself, at which the
-    *  claim can be verified using the call data.
+    *  issuer: The issuers identity contract address. It should hold the key with which the above message was signed,
+    *  if the key is not present anymore, the claim SHOULD be treated as invalid. The claim is verified by calling
+    *  {IClaimIssuer-isClaimValid} of this address, unless the issuer is the identity holding the claim.
     *  signature: Signature which is the proof that the claim issuer issued a claim of topic for this identity.
     *  data: The hash of the claim data, sitting in another location, a bit-mask, call data, or actual data based on
     *  the claim scheme.
     *  uri: The location of the claim, this can be HTTP links, swarm hashes, IPFS hashes, and such.
+    *  valid_from: The ledger timestamp from which the claim is valid, 0 if valid right away.
+    *  valid_until: The ledger timestamp until which the claim is valid, 0 if it does not expire.
     */
 #[derive(Clone)]
 #[contracttype]
 pub struct Claim {
-        pub topic: u32,
-        pub scheme: u32,
-        pub issuer: BytesN<32>,
-        pub signature: BytesN<64>,
+        pub topic: u128,
+        pub scheme: u128,
+        pub issuer: Address,
+        pub signature: Bytes,
         pub data: Bytes,
         pub uri: String,
+        pub valid_from: u64,
+        pub valid_until: u64,
+    }
+
+   /**
+    *  Definition of the signature of a key authorizing an invocation on behalf of the identity.
+    *
+    *  key: the key of the identity which signed the authorization payload
+    *  signature: the signature of the payload, in the format of the key type
+    */
+#[derive(Clone)]
+#[contracttype]
+pub struct KeySignature {
+        pub key: BytesN<32>,
+        pub signature: Bytes,
+    }
+
+   /**
+    *  Definition of an implementation of the identity contract approved by an implementation authority.
+    *
+    *  wasm_hash: the hash of the uploaded wasm of the implementation
+    *  version: the contract version of the implementation, see {Identity-get_version}, increased by each approved implementation
+    */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Implementation {
+        pub wasm_hash: BytesN<32>,
+        pub version: u32,
+    }
+
+   /**
+    *  Definition of a claim topic required by a claim topics registry.
+    *
+    *  topic: the topic of the claims, as in {Claim}
+    *  name: the name of the topic, e.g. COUNTRY
+    *  scheme: the name of the scheme of the claims of the topic, e.g. STRING
+    */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ClaimTopic {
+        pub topic: u128,
+        pub name: String,
+        pub scheme: String,
+    }
+
+   /**
+    *  Outcome of the verification of a claim topic of an identity, see {verifier-verify_topic}.
+    *
+    *  Valid: the identity holds a valid claim of the topic issued by a trusted issuer
+    *  NoClaim: the identity holds no claim of the topic
+    *  UnknownIssuer: the identity only holds claims of the topic issued by issuers which are not trusted
+    *  NotYetValid: the claims of trusted issuers are not valid yet, their validity window starting later
+    *  Expired: the claims of trusted issuers are past their validity window
+    *  Revoked: the claims of trusted issuers were revoked by their issuer
+    *  Invalid: the claims of trusted issuers are rejected by their issuer, e.g. signed by a key it removed
+    */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ClaimStatus {
+        Valid,
+        NoClaim,
+        UnknownIssuer,
+        NotYetValid,
+        Expired,
+        Revoked,
+        Invalid,
+    }
+
+   /**
+    *  Definition of the verification of a claim topic of an identity.
+    *
+    *  topic: the verified claim topic
+    *  status: the outcome of the verification
+    */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TopicVerification {
+        pub topic: u128,
+        pub status: ClaimStatus,
+    }
+
+   /**
+    *  Definition of the verification of an identity against required claim topics.
+    *
+    *  verified: TRUE if every required topic is Valid
+    *  topics: the verification of each required topic, in the order of the required topics
+    */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Verification {
+        pub verified: bool,
+        pub topics: Vec<TopicVerification>,
+    }
+
+   /**
+    *  Definition of the TTL configuration of the identity, in ledgers.
+    *
+    *  threshold: persistent entries and the instance are extended when their TTL falls below this value
+    *  extend_to: the TTL the entries are extended to
+    */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TtlConfig {
+        pub threshold: u32,
+        pub extend_to: u32,
     }
 
 
@@ -80,5 +188,15 @@ pub enum DataKey {
     Key(BytesN<32>),
     Purpose(u32),
     Claim(BytesN<32>),
-    ClaimTopic(u32)
-}
\ No newline at end of file
+    ClaimTopic(u128),
+    Owner,
+    Execution(u64),
+    ExecutionNonce,
+    TtlConfig,
+    Purposes,
+    ClaimTopics,
+    RevokedClaim(BytesN<32>),
+    Version,
+    ImplementationAuthority,
+    ImplementationVersion
+}
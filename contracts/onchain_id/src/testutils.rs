@@ -0,0 +1,85 @@
+extern crate std;
+
+pub use ed25519_dalek::Keypair;
+
+use ed25519_dalek::Signer;
+use rand::thread_rng;
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _, ContractFunctionSet, Events}, vec, Address, Bytes, BytesN, Env, IntoVal, String,
+    Symbol, Val, Vec,
+};
+
+use crate::{claims_issuer::claim_message, erc734::{CLAIM_SIGNER_KEY, ED25519_KEY_TYPE}, key_store::address_to_key};
+
+/**
+ * Returns the last event published in the test environment.
+ */
+pub fn last_event(env: &Env) -> Vec<(Address, Vec<Val>, Val)> {
+    let events = env.events().all();
+    events.slice(events.len() - 1..)
+}
+
+/**
+ * Registers an identity contract initialized with the MANAGEMENT key of a new owner address.
+ * @param _contract the identity contract, e.g. Identity of the identity crate
+ * @return identity, owner Returns the address of the identity and of its owner
+ */
+pub fn register_identity<T: ContractFunctionSet + 'static>(env: &Env, contract: T) -> (Address, Address) {
+    let identity = env.register_contract(None, contract);
+    let owner = Address::generate(env);
+    env.invoke_contract::<()>(&identity, &Symbol::new(env, "initialize"), vec![env, address_to_key(env, &owner).into_val(env), owner.into_val(env)]);
+    (identity, owner)
+}
+
+/**
+ * Registers a claim issuer contract deployed and initialized by a new owner address, with a new ed25519 CLAIM key.
+ * The authorizations of the owner must be mocked.
+ * @param _contract the claim issuer contract, e.g. ClaimIssuer of the claim_issuer crate
+ * @return issuer, owner, keyPair Returns the address of the claim issuer, of its owner and the key pair of its CLAIM key
+ */
+pub fn register_claim_issuer<T: ContractFunctionSet + 'static>(env: &Env, contract: T) -> (Address, Address, Keypair) {
+    let owner = Address::generate(env);
+    let salt = BytesN::<32>::random(env);
+    let issuer = env.register_contract(Some(&env.deployer().with_address(owner.clone(), salt.clone()).deployed_address()), contract);
+    env.invoke_contract::<()>(&issuer, &Symbol::new(env, "initialize"), vec![env, address_to_key(env, &owner).into_val(env), owner.into_val(env), salt.into_val(env)]);
+
+    let key_pair = Keypair::generate(&mut thread_rng());
+    let key = BytesN::from_array(env, key_pair.public.as_bytes());
+    env.invoke_contract::<bool>(&issuer, &Symbol::new(env, "add_key"), vec![env, owner.into_val(env), key.into_val(env), CLAIM_SIGNER_KEY.into_val(env), ED25519_KEY_TYPE.into_val(env)]);
+    (issuer, owner, key_pair)
+}
+
+/**
+ * Signs the message of a claim with an ed25519 CLAIM key, see {claim_message}. The signature is prefixed
+ * with the public key of the key pair.
+ */
+pub fn sign_claim(env: &Env, key_pair: &Keypair, identity: &Address, topic: u128, data: &Bytes, valid_from: u64, valid_until: u64) -> Bytes {
+    let message: std::vec::Vec<u8> = claim_message(env, identity, topic, data, valid_from, valid_until).iter().collect();
+    let mut signature = Bytes::from_array(env, key_pair.public.as_bytes());
+    signature.extend_from_array(&key_pair.sign(&message).to_bytes());
+    signature
+}
+
+/**
+ * Adds to `identity` a claim of `topic` with the data "true", signed by the CLAIM key of `issuer`.
+ * The authorizations of the owner must be mocked.
+ * @param _owner address holding a MANAGEMENT key of the identity
+ * @return signature Returns the signature of the claim
+ */
+pub fn add_claim(env: &Env, identity: &Address, owner: &Address, issuer: &Address, key_pair: &Keypair, topic: u128, valid_from: u64, valid_until: u64) -> Bytes {
+    let data = Bytes::from_slice(env, "true".as_bytes());
+    let signature = sign_claim(env, key_pair, identity, topic, &data, valid_from, valid_until);
+    env.invoke_contract::<BytesN<32>>(identity, &Symbol::new(env, "add_claim"), vec![
+        env,
+        owner.into_val(env),
+        topic.into_val(env),
+        1_u128.into_val(env),
+        issuer.into_val(env),
+        signature.into_val(env),
+        data.into_val(env),
+        String::from_str(env, "").into_val(env),
+        valid_from.into_val(env),
+        valid_until.into_val(env),
+    ]);
+    signature
+}
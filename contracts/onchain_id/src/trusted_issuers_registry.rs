@@ -0,0 +1,34 @@
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+/**
+ * Interface of the trusted issuers registry, listing the claim issuers trusted by the verifiers and the
+ * claim topics each of them may attest, mirroring the TrustedIssuersRegistry contract of T-REX.
+ * The issuers are the addresses of the contracts issuing the claims, see {Claim-issuer}.
+ */
+#[contractclient(name = "TrustedIssuersRegistryClient")]
+pub trait ITrustedIssuersRegistry {
+    /**
+     * Returns the trusted issuers, in the order they were added.
+     */
+    fn get_trusted_issuers(e: Env) -> Vec<Address>;
+
+    /**
+     * Returns TRUE if `issuer` is a trusted issuer.
+     */
+    fn is_trusted_issuer(e: Env, issuer: Address) -> bool;
+
+    /**
+     * Returns the claim topics `issuer` may attest, empty if it is not a trusted issuer.
+     */
+    fn get_trusted_issuer_claim_topics(e: Env, issuer: Address) -> Vec<u128>;
+
+    /**
+     * Returns the trusted issuers which may attest `topic`.
+     */
+    fn get_trusted_issuers_for_topic(e: Env, topic: u128) -> Vec<Address>;
+
+    /**
+     * Returns TRUE if `issuer` is a trusted issuer which may attest `topic`.
+     */
+    fn has_claim_topic(e: Env, issuer: Address, topic: u128) -> bool;
+}
@@ -0,0 +1,114 @@
+use soroban_sdk::{contractclient, vec, Address, BytesN, Env, Vec};
+
+use crate::{
+    claims_issuer::{is_within_validity, traits::ClaimIssuerClient}, erc735::traits::ERC735Client, error::OnChainIdError,
+    structs::{ClaimStatus, TopicVerification, Verification},
+};
+
+/**
+ * Functions of the identity contract read by the verification besides those of ERC-735.
+ */
+#[contractclient(name = "IdentityClaimsClient")]
+pub trait IIdentityClaims {
+    fn get_claim_validity(e: Env, claim_id: BytesN<32>) -> Result<(u64, u64), OnChainIdError>;
+}
+
+/**
+ * Verifies that `identity` holds a valid claim of each of `topics` issued by one of `trusted_issuers`.
+ * Usable by any contract checking identities, see {Verifier-verify}.
+ * @param _identity the identity contract to verify
+ * @param _topics the required claim topics
+ * @param _trustedIssuers the claim issuer contracts trusted for every required topic
+ * @return verification Returns whether the identity is verified, with the outcome for each topic
+ */
+pub fn verify(e: &Env, identity: &Address, topics: &Vec<u128>, trusted_issuers: &Vec<Address>) -> Verification {
+    let mut verification = Verification { verified: true, topics: vec![e] };
+    for topic in topics.iter() {
+        let status = verify_topic(e, identity, topic, trusted_issuers);
+        verification.verified &= status == ClaimStatus::Valid;
+        verification.topics.push_back(TopicVerification { topic, status });
+    }
+    verification
+}
+
+/**
+ * Verifies that `identity` holds a valid claim of `topic` issued by one of `trusted_issuers`.
+ * Each claim of the topic is read from the identity with get_claim_ids_by_topic and get_claim, then
+ * checked against its validity window, the revocations of its issuer and is_claim_valid of its issuer.
+ * When no claim is valid, the outcome of the claims of trusted issuers is reported, NotYetValid first,
+ * then Expired, then Revoked, then Invalid. Claims the identity or the issuer fail to return or check are Invalid.
+ * @param _identity the identity contract to verify
+ * @param _topic the required claim topic
+ * @param _trustedIssuers the claim issuer contracts trusted for the topic
+ * @return status Returns the outcome of the verification
+ */
+pub fn verify_topic(e: &Env, identity: &Address, topic: u128, trusted_issuers: &Vec<Address>) -> ClaimStatus {
+    let identity_client = ERC735Client::new(e, identity);
+    let claim_ids = match identity_client.try_get_claim_ids_by_topic(&topic) {
+        Ok(Ok(claim_ids)) => claim_ids,
+        _ => return ClaimStatus::NoClaim,
+    };
+    if claim_ids.is_empty() {
+        return ClaimStatus::NoClaim;
+    }
+
+    let mut status = ClaimStatus::UnknownIssuer;
+    for claim_id in claim_ids.iter() {
+        let claim_status = verify_claim(e, identity, topic, &claim_id, trusted_issuers);
+        if claim_status == ClaimStatus::Valid {
+            return ClaimStatus::Valid;
+        }
+        if status_priority(claim_status) > status_priority(status) {
+            status = claim_status;
+        }
+    }
+    status
+}
+
+/**
+ * Returns the outcome of the verification of the claim `claim_id` of `identity`.
+ */
+fn verify_claim(e: &Env, identity: &Address, topic: u128, claim_id: &BytesN<32>, trusted_issuers: &Vec<Address>) -> ClaimStatus {
+    let (issuer, signature, data) = match ERC735Client::new(e, identity).try_get_claim(claim_id) {
+        Ok(Ok((_, _, issuer, signature, data, _))) => (issuer, signature, data),
+        _ => return ClaimStatus::Invalid,
+    };
+    if !trusted_issuers.contains(&issuer) {
+        return ClaimStatus::UnknownIssuer;
+    }
+
+    let (valid_from, valid_until) = match IdentityClaimsClient::new(e, identity).try_get_claim_validity(claim_id) {
+        Ok(Ok(validity)) => validity,
+        _ => (0, 0),
+    };
+    if valid_from > e.ledger().timestamp() {
+        return ClaimStatus::NotYetValid;
+    }
+    if !is_within_validity(e, valid_from, valid_until) {
+        return ClaimStatus::Expired;
+    }
+
+    let issuer_client = ClaimIssuerClient::new(e, &issuer);
+    if let Ok(Ok(true)) = issuer_client.try_is_claim_revoked(&signature) {
+        return ClaimStatus::Revoked;
+    }
+    match issuer_client.try_is_claim_valid(identity, &topic, &signature, &data, &valid_from, &valid_until) {
+        Ok(Ok(true)) => ClaimStatus::Valid,
+        _ => ClaimStatus::Invalid,
+    }
+}
+
+/**
+ * Returns how close to valid a claim with `status` is, to report the closest claim of a topic.
+ */
+fn status_priority(status: ClaimStatus) -> u32 {
+    match status {
+        ClaimStatus::Valid => 6,
+        ClaimStatus::NotYetValid => 5,
+        ClaimStatus::Expired => 4,
+        ClaimStatus::Revoked => 3,
+        ClaimStatus::Invalid => 2,
+        ClaimStatus::UnknownIssuer => 1,
+        ClaimStatus::NoClaim => 0,
+    }
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PermissionedTokenError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    AgentAlreadyAdded = 4,
+    AgentNotAdded = 5,
+    NegativeAmount = 6,
+    InsufficientBalance = 7,
+    InsufficientAllowance = 8,
+    InvalidExpirationLedger = 9,
+    Paused = 10,
+    NotPaused = 11,
+    FrozenWallet = 12,
+    UnverifiedWallet = 13,
+    NonCompliantTransfer = 14,
+    WalletNotRecoverable = 15
+}
@@ -0,0 +1,105 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+/**
+ * Emitted when an allowance is set, as specified by SEP-41.
+ * Topics: ("approve", from, spender). Data: (amount, expiration_ledger).
+ */
+pub(crate) fn approve(e: &Env, from: &Address, spender: &Address, amount: i128, expiration_ledger: u32) {
+    e.events().publish((symbol_short!("approve"), from.clone(), spender.clone()), (amount, expiration_ledger));
+}
+
+/**
+ * Emitted when tokens are transferred, forced transfers and recoveries included, as specified by SEP-41.
+ * Topics: ("transfer", from, to). Data: amount.
+ */
+pub(crate) fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+    e.events().publish((symbol_short!("transfer"), from.clone(), to.clone()), amount);
+}
+
+/**
+ * Emitted when tokens are minted by an agent, as specified by SEP-41.
+ * Topics: ("mint", agent, to). Data: amount.
+ */
+pub(crate) fn mint(e: &Env, agent: &Address, to: &Address, amount: i128) {
+    e.events().publish((symbol_short!("mint"), agent.clone(), to.clone()), amount);
+}
+
+/**
+ * Emitted when tokens are burnt by their holder or a spender, as specified by SEP-41.
+ * Topics: ("burn", from). Data: amount.
+ */
+pub(crate) fn burn(e: &Env, from: &Address, amount: i128) {
+    e.events().publish((symbol_short!("burn"), from.clone()), amount);
+}
+
+/**
+ * Emitted when tokens are burnt by an agent, as specified by SEP-41.
+ * Topics: ("clawback", agent, from). Data: amount.
+ */
+pub(crate) fn clawback(e: &Env, agent: &Address, from: &Address, amount: i128) {
+    e.events().publish((symbol_short!("clawback"), agent.clone(), from.clone()), amount);
+}
+
+/**
+ * Emitted when an agent is added to the token.
+ * Topics: ("AgentAdded", agent). Data: ().
+ */
+pub(crate) fn agent_added(e: &Env, agent: &Address) {
+    e.events().publish((Symbol::new(e, "AgentAdded"), agent.clone()), ());
+}
+
+/**
+ * Emitted when an agent is removed from the token.
+ * Topics: ("AgentRemoved", agent). Data: ().
+ */
+pub(crate) fn agent_removed(e: &Env, agent: &Address) {
+    e.events().publish((Symbol::new(e, "AgentRemoved"), agent.clone()), ());
+}
+
+/**
+ * Emitted when a wallet is frozen or unfrozen.
+ * Topics: ("AddressFrozen", wallet, frozen). Data: the agent.
+ */
+pub(crate) fn address_frozen(e: &Env, wallet: &Address, frozen: bool, agent: &Address) {
+    e.events().publish((Symbol::new(e, "AddressFrozen"), wallet.clone(), frozen), agent.clone());
+}
+
+/**
+ * Emitted when tokens of a wallet are frozen.
+ * Topics: ("TokensFrozen", wallet). Data: amount.
+ */
+pub(crate) fn tokens_frozen(e: &Env, wallet: &Address, amount: i128) {
+    e.events().publish((Symbol::new(e, "TokensFrozen"), wallet.clone()), amount);
+}
+
+/**
+ * Emitted when tokens of a wallet are unfrozen.
+ * Topics: ("TokensUnfrozen", wallet). Data: amount.
+ */
+pub(crate) fn tokens_unfrozen(e: &Env, wallet: &Address, amount: i128) {
+    e.events().publish((Symbol::new(e, "TokensUnfrozen"), wallet.clone()), amount);
+}
+
+/**
+ * Emitted when the token is paused.
+ * Topics: ("Paused",). Data: the agent.
+ */
+pub(crate) fn paused(e: &Env, agent: &Address) {
+    e.events().publish((Symbol::new(e, "Paused"),), agent.clone());
+}
+
+/**
+ * Emitted when the token is unpaused.
+ * Topics: ("Unpaused",). Data: the agent.
+ */
+pub(crate) fn unpaused(e: &Env, agent: &Address) {
+    e.events().publish((Symbol::new(e, "Unpaused"),), agent.clone());
+}
+
+/**
+ * Emitted when the tokens of a lost wallet are recovered to a new wallet of the same identity.
+ * Topics: ("RecoverySuccess", lost_wallet, new_wallet). Data: the identity.
+ */
+pub(crate) fn recovery_success(e: &Env, lost_wallet: &Address, new_wallet: &Address, identity: &Address) {
+    e.events().publish((Symbol::new(e, "RecoverySuccess"), lost_wallet.clone(), new_wallet.clone()), identity.clone());
+}
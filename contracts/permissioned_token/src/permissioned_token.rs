@@ -0,0 +1,663 @@
+use soroban_sdk::{contract, contractclient, contractimpl, contracttype, panic_with_error, token::TokenInterface, Address, Env, String};
+use onchain_id::{
+    compliance::ComplianceClient, erc734::{traits::ERC734Client, MANAGEMENT_KEY}, identity_registry::IdentityRegistryClient,
+    key_store::address_to_key, storage::{extend_instance, get_persistent, set_persistent},
+};
+
+use crate::{error::PermissionedTokenError, events};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Name,
+    Symbol,
+    Decimals,
+    IdentityRegistry,
+    Compliance,
+    Paused,
+    TotalSupply,
+    Agent(Address),
+    Balance(Address),
+    Allowance(Address, Address),
+    Frozen(Address),
+    FrozenTokens(Address),
+}
+
+/**
+ * Allowance of a spender, which expires after `expiration_ledger`.
+ */
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/**
+ * Functions of the identity registry called by the token to recover lost wallets, which requires the
+ * token to be an agent of the registry.
+ */
+#[contractclient(name = "IdentityRegistryAgentClient")]
+pub trait IIdentityRegistryAgent {
+    fn register_identity(e: Env, caller: Address, wallet: Address, identity: Address, country: u32);
+
+    fn delete_identity(e: Env, caller: Address, wallet: Address);
+}
+
+/**
+ * SEP-41 token restricted to verified investors, mirroring the Token contract of ERC-3643 (T-REX).
+ * The tokens are only transferred and minted to wallets verified by the identity registry, when the
+ * compliance allows it. The agents of the token mint and burn the tokens, freeze wallets or part of
+ * their tokens, force transfers, pause the token, and recover the tokens of the lost wallets of an
+ * identity.
+ */
+#[contract]
+pub struct PermissionedToken;
+
+#[contractimpl]
+impl PermissionedToken {
+    /**
+    * Initializes the token with its admin, its metadata, its identity registry and its compliance.
+    * Can only be called once, right after the deployment of the token. The compliance must then be bound
+    * to the token, see {Compliance-bind_token}.
+    * @param _admin address managing the agents, which must authorize the initialization
+    * @param _decimals number of decimals of the amounts
+    * @param _name name of the token
+    * @param _symbol symbol of the token
+    * @param _identityRegistry the identity registry verifying the investors
+    * @param _compliance the compliance checking the transfers and the mints
+    */
+    pub fn initialize(e: Env, admin: Address, decimals: u32, name: String, symbol: String, identity_registry: Address, compliance: Address) -> Result<(), PermissionedTokenError> {
+        if e.storage().instance().has(&DataKey::Admin) {
+            return Err(PermissionedTokenError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::Decimals, &decimals);
+        e.storage().instance().set(&DataKey::Name, &name);
+        e.storage().instance().set(&DataKey::Symbol, &symbol);
+        e.storage().instance().set(&DataKey::IdentityRegistry, &identity_registry);
+        e.storage().instance().set(&DataKey::Compliance, &compliance);
+        extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Adds an agent of the token.
+    * MUST only be done by the admin of the token.
+    * @param _agent the address of the agent
+    */
+    pub fn add_agent(e: Env, agent: Address) -> Result<(), PermissionedTokenError> {
+        get_admin(&e)?.require_auth();
+
+        let agent_key = DataKey::Agent(agent.clone());
+        if e.storage().persistent().has(&agent_key) {
+            return Err(PermissionedTokenError::AgentAlreadyAdded);
+        }
+
+        set_persistent(&e, &agent_key, &true);
+        events::agent_added(&e, &agent);
+        Ok(())
+    }
+
+    /**
+    * Removes an agent of the token.
+    * MUST only be done by the admin of the token.
+    * @param _agent the address of the agent
+    */
+    pub fn remove_agent(e: Env, agent: Address) -> Result<(), PermissionedTokenError> {
+        get_admin(&e)?.require_auth();
+
+        let agent_key = DataKey::Agent(agent.clone());
+        if !e.storage().persistent().has(&agent_key) {
+            return Err(PermissionedTokenError::AgentNotAdded);
+        }
+
+        e.storage().persistent().remove(&agent_key);
+        events::agent_removed(&e, &agent);
+        Ok(())
+    }
+
+    /**
+    * Mints tokens to a verified wallet, if the compliance allows it.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    * @param _to the wallet receiving the tokens
+    * @param _amount the amount of tokens
+    */
+    pub fn mint(e: Env, caller: Address, to: Address, amount: i128) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+        check_amount(amount)?;
+
+        if !identity_registry(&e)?.is_verified(&to) {
+            return Err(PermissionedTokenError::UnverifiedWallet);
+        }
+        let compliance = compliance(&e)?;
+        if !compliance.can_create(&to, &amount) {
+            return Err(PermissionedTokenError::NonCompliantTransfer);
+        }
+
+        set_balance(&e, &to, balance(&e, &to) + amount);
+        e.storage().instance().set(&DataKey::TotalSupply, &(total_supply(&e) + amount));
+        compliance.created(&to, &amount);
+        events::mint(&e, &caller, &to, amount);
+        Ok(())
+    }
+
+    /**
+    * Burns tokens of a wallet, frozen tokens included, the frozen tokens burnt being unfrozen.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    * @param _from the wallet holding the tokens
+    * @param _amount the amount of tokens
+    */
+    pub fn clawback(e: Env, caller: Address, from: Address, amount: i128) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+        check_amount(amount)?;
+
+        unfreeze_spent_tokens(&e, &from, amount)?;
+        burn_tokens(&e, &from, amount)?;
+        events::clawback(&e, &caller, &from, amount);
+        Ok(())
+    }
+
+    /**
+    * Transfers tokens of a wallet to a verified wallet, even if the wallets or the tokens are frozen or if
+    * the compliance does not allow it, the frozen tokens transferred being unfrozen.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    * @param _from the wallet holding the tokens
+    * @param _to the wallet receiving the tokens
+    * @param _amount the amount of tokens
+    */
+    pub fn forced_transfer(e: Env, caller: Address, from: Address, to: Address, amount: i128) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+        check_amount(amount)?;
+
+        if !identity_registry(&e)?.is_verified(&to) {
+            return Err(PermissionedTokenError::UnverifiedWallet);
+        }
+        unfreeze_spent_tokens(&e, &from, amount)?;
+        move_tokens(&e, &from, &to, amount)
+    }
+
+    /**
+    * Freezes or unfreezes a wallet, which cannot send nor receive tokens while frozen.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    * @param _wallet the wallet to freeze or unfreeze
+    * @param _freeze TRUE to freeze the wallet, FALSE to unfreeze it
+    */
+    pub fn set_address_frozen(e: Env, caller: Address, wallet: Address, freeze: bool) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+
+        let frozen_key = DataKey::Frozen(wallet.clone());
+        if freeze {
+            set_persistent(&e, &frozen_key, &true);
+        } else {
+            e.storage().persistent().remove(&frozen_key);
+        }
+        events::address_frozen(&e, &wallet, freeze, &caller);
+        Ok(())
+    }
+
+    /**
+    * Freezes part of the tokens of a wallet, which cannot be transferred until unfrozen.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    * @param _wallet the wallet holding the tokens
+    * @param _amount the amount of tokens to freeze, at most the tokens of the wallet which are not frozen
+    */
+    pub fn freeze_partial_tokens(e: Env, caller: Address, wallet: Address, amount: i128) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+        check_amount(amount)?;
+
+        let frozen_tokens = frozen_tokens(&e, &wallet);
+        if balance(&e, &wallet) - frozen_tokens < amount {
+            return Err(PermissionedTokenError::InsufficientBalance);
+        }
+
+        set_frozen_tokens(&e, &wallet, frozen_tokens + amount);
+        events::tokens_frozen(&e, &wallet, amount);
+        Ok(())
+    }
+
+    /**
+    * Unfreezes part of the frozen tokens of a wallet.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    * @param _wallet the wallet holding the tokens
+    * @param _amount the amount of tokens to unfreeze, at most the frozen tokens of the wallet
+    */
+    pub fn unfreeze_partial_tokens(e: Env, caller: Address, wallet: Address, amount: i128) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+        check_amount(amount)?;
+
+        let frozen_tokens = frozen_tokens(&e, &wallet);
+        if frozen_tokens < amount {
+            return Err(PermissionedTokenError::InsufficientBalance);
+        }
+
+        set_frozen_tokens(&e, &wallet, frozen_tokens - amount);
+        events::tokens_unfrozen(&e, &wallet, amount);
+        Ok(())
+    }
+
+    /**
+    * Pauses the token, whose holders can no longer transfer their tokens.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    */
+    pub fn pause(e: Env, caller: Address) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+
+        if is_paused(&e) {
+            return Err(PermissionedTokenError::Paused);
+        }
+
+        e.storage().instance().set(&DataKey::Paused, &true);
+        extend_instance(&e);
+        events::paused(&e, &caller);
+        Ok(())
+    }
+
+    /**
+    * Unpauses the token.
+    * MUST only be done by an agent of the token.
+    * @param _caller the agent, which must authorize the call
+    */
+    pub fn unpause(e: Env, caller: Address) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+
+        if !is_paused(&e) {
+            return Err(PermissionedTokenError::NotPaused);
+        }
+
+        e.storage().instance().set(&DataKey::Paused, &false);
+        extend_instance(&e);
+        events::unpaused(&e, &caller);
+        Ok(())
+    }
+
+    /**
+    * Recovers the tokens of a lost wallet to a new wallet of the same identity. The new wallet is registered
+    * in the identity registry with the identity and the country of the lost wallet, which is removed from the
+    * registry. The frozen tokens and the frozen status of the lost wallet are carried over.
+    * MUST only be done by an agent of the token, the token being an agent of the identity registry.
+    * @param _caller the agent, which must authorize the call
+    * @param _lostWallet the wallet the investor lost the keys of
+    * @param _newWallet the new wallet, whose key must hold a MANAGEMENT key of the identity
+    * @param _identity the identity the lost wallet is registered with
+    */
+    pub fn recovery_address(e: Env, caller: Address, lost_wallet: Address, new_wallet: Address, identity: Address) -> Result<(), PermissionedTokenError> {
+        require_agent(&e, &caller)?;
+
+        let identity_registry = identity_registry(&e)?;
+        let country = match identity_registry.get_identity(&lost_wallet) {
+            Some(lost_identity) if lost_identity == identity => identity_registry.get_investor_country(&lost_wallet).unwrap_or(0),
+            _ => return Err(PermissionedTokenError::WalletNotRecoverable),
+        };
+        match ERC734Client::new(&e, &identity).try_key_has_purpose(&address_to_key(&e, &new_wallet), &MANAGEMENT_KEY) {
+            Ok(Ok(true)) => {}
+            _ => return Err(PermissionedTokenError::WalletNotRecoverable),
+        }
+
+        // The new wallet is registered before the transfer, so the compliance sees both wallets as the same identity
+        let registry_agent = IdentityRegistryAgentClient::new(&e, &identity_registry.address);
+        if !identity_registry.contains(&new_wallet) {
+            registry_agent.register_identity(&e.current_contract_address(), &new_wallet, &identity, &country);
+        }
+
+        let lost_frozen_tokens = frozen_tokens(&e, &lost_wallet);
+        move_tokens(&e, &lost_wallet, &new_wallet, balance(&e, &lost_wallet))?;
+        if lost_frozen_tokens > 0 {
+            set_frozen_tokens(&e, &lost_wallet, 0);
+            set_frozen_tokens(&e, &new_wallet, frozen_tokens(&e, &new_wallet) + lost_frozen_tokens);
+        }
+        if is_frozen(&e, &lost_wallet) {
+            set_persistent(&e, &DataKey::Frozen(new_wallet.clone()), &true);
+        }
+
+        registry_agent.delete_identity(&e.current_contract_address(), &lost_wallet);
+        events::recovery_success(&e, &lost_wallet, &new_wallet, &identity);
+        Ok(())
+    }
+
+    /**
+    * Returns TRUE if `agent` is an agent of the token.
+    */
+    pub fn is_agent(e: Env, agent: Address) -> bool {
+        is_agent(&e, &agent)
+    }
+
+    /**
+    * Returns TRUE if the token is paused.
+    */
+    pub fn is_paused(e: Env) -> bool {
+        is_paused(&e)
+    }
+
+    /**
+    * Returns TRUE if `wallet` is frozen.
+    */
+    pub fn is_frozen(e: Env, wallet: Address) -> bool {
+        is_frozen(&e, &wallet)
+    }
+
+    /**
+    * Returns the frozen tokens of `wallet`.
+    */
+    pub fn get_frozen_tokens(e: Env, wallet: Address) -> i128 {
+        frozen_tokens(&e, &wallet)
+    }
+
+    /**
+    * Returns the total supply of the token.
+    */
+    pub fn total_supply(e: Env) -> i128 {
+        total_supply(&e)
+    }
+
+    /**
+    * Returns the identity registry of the token.
+    */
+    pub fn get_identity_registry(e: Env) -> Result<Address, PermissionedTokenError> {
+        Ok(identity_registry(&e)?.address)
+    }
+
+    /**
+    * Returns the compliance of the token.
+    */
+    pub fn get_compliance(e: Env) -> Result<Address, PermissionedTokenError> {
+        Ok(compliance(&e)?.address)
+    }
+
+    /**
+    * Returns the admin of the token.
+    */
+    pub fn get_admin(e: Env) -> Result<Address, PermissionedTokenError> {
+        get_admin(&e)
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for PermissionedToken {
+    /**
+     * See {TokenInterface-allowance}.
+     */
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        allowance(&e, &from, &spender)
+    }
+
+    /**
+     * See {TokenInterface-approve}.
+     */
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        unwrap_or_panic(&e, check_amount(amount));
+
+        if amount > 0 && expiration_ledger < e.ledger().sequence() {
+            panic_with_error!(&e, PermissionedTokenError::InvalidExpirationLedger);
+        }
+
+        let allowance_key = DataKey::Allowance(from.clone(), spender.clone());
+        e.storage().temporary().set(&allowance_key, &AllowanceValue { amount, expiration_ledger });
+        if amount > 0 {
+            let live_for = expiration_ledger - e.ledger().sequence();
+            e.storage().temporary().extend_ttl(&allowance_key, live_for, live_for);
+        }
+        events::approve(&e, &from, &spender, amount, expiration_ledger);
+    }
+
+    /**
+     * See {TokenInterface-balance}.
+     */
+    fn balance(e: Env, id: Address) -> i128 {
+        balance(&e, &id)
+    }
+
+    /**
+     * See {TokenInterface-transfer}. Both wallets must be verified and the compliance must allow the transfer.
+     */
+    fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+
+        unwrap_or_panic(&e, transfer(&e, &from, &to, amount));
+    }
+
+    /**
+     * See {TokenInterface-transfer_from}. Both wallets must be verified and the compliance must allow the transfer.
+     */
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+
+        unwrap_or_panic(&e, spend_allowance(&e, &from, &spender, amount).and_then(|_| transfer(&e, &from, &to, amount)));
+    }
+
+    /**
+     * See {TokenInterface-burn}.
+     */
+    fn burn(e: Env, from: Address, amount: i128) {
+        from.require_auth();
+
+        unwrap_or_panic(&e, burn(&e, &from, amount));
+    }
+
+    /**
+     * See {TokenInterface-burn_from}.
+     */
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+
+        unwrap_or_panic(&e, spend_allowance(&e, &from, &spender, amount).and_then(|_| burn(&e, &from, amount)));
+    }
+
+    /**
+     * See {TokenInterface-decimals}.
+     */
+    fn decimals(e: Env) -> u32 {
+        e.storage().instance().get(&DataKey::Decimals).unwrap_or(0)
+    }
+
+    /**
+     * See {TokenInterface-name}.
+     */
+    fn name(e: Env) -> String {
+        e.storage().instance().get(&DataKey::Name).unwrap_or(String::from_str(&e, ""))
+    }
+
+    /**
+     * See {TokenInterface-symbol}.
+     */
+    fn symbol(e: Env) -> String {
+        e.storage().instance().get(&DataKey::Symbol).unwrap_or(String::from_str(&e, ""))
+    }
+}
+
+fn unwrap_or_panic<T>(e: &Env, result: Result<T, PermissionedTokenError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => panic_with_error!(e, error),
+    }
+}
+
+fn get_admin(e: &Env) -> Result<Address, PermissionedTokenError> {
+    e.storage().instance().get(&DataKey::Admin).ok_or(PermissionedTokenError::NotInitialized)
+}
+
+fn identity_registry(e: &Env) -> Result<IdentityRegistryClient<'_>, PermissionedTokenError> {
+    let identity_registry: Address = e.storage().instance().get(&DataKey::IdentityRegistry).ok_or(PermissionedTokenError::NotInitialized)?;
+    Ok(IdentityRegistryClient::new(e, &identity_registry))
+}
+
+fn compliance(e: &Env) -> Result<ComplianceClient<'_>, PermissionedTokenError> {
+    let compliance: Address = e.storage().instance().get(&DataKey::Compliance).ok_or(PermissionedTokenError::NotInitialized)?;
+    Ok(ComplianceClient::new(e, &compliance))
+}
+
+fn is_agent(e: &Env, agent: &Address) -> bool {
+    get_persistent::<bool>(e, &DataKey::Agent(agent.clone())).unwrap_or(false)
+}
+
+/**
+ * Requires the authorization of `caller` and checks that it is an agent of the token.
+ */
+fn require_agent(e: &Env, caller: &Address) -> Result<(), PermissionedTokenError> {
+    caller.require_auth();
+    if !is_agent(e, caller) {
+        return Err(PermissionedTokenError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn check_amount(amount: i128) -> Result<(), PermissionedTokenError> {
+    if amount < 0 {
+        return Err(PermissionedTokenError::NegativeAmount);
+    }
+    Ok(())
+}
+
+fn is_paused(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+fn is_frozen(e: &Env, wallet: &Address) -> bool {
+    get_persistent::<bool>(e, &DataKey::Frozen(wallet.clone())).unwrap_or(false)
+}
+
+fn total_supply(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
+}
+
+fn balance(e: &Env, wallet: &Address) -> i128 {
+    get_persistent(e, &DataKey::Balance(wallet.clone())).unwrap_or(0)
+}
+
+fn set_balance(e: &Env, wallet: &Address, amount: i128) {
+    set_persistent(e, &DataKey::Balance(wallet.clone()), &amount);
+}
+
+fn frozen_tokens(e: &Env, wallet: &Address) -> i128 {
+    get_persistent(e, &DataKey::FrozenTokens(wallet.clone())).unwrap_or(0)
+}
+
+fn set_frozen_tokens(e: &Env, wallet: &Address, amount: i128) {
+    set_persistent(e, &DataKey::FrozenTokens(wallet.clone()), &amount);
+}
+
+fn allowance(e: &Env, from: &Address, spender: &Address) -> i128 {
+    match e.storage().temporary().get::<DataKey, AllowanceValue>(&DataKey::Allowance(from.clone(), spender.clone())) {
+        Some(allowance) if allowance.expiration_ledger >= e.ledger().sequence() => allowance.amount,
+        _ => 0,
+    }
+}
+
+fn spend_allowance(e: &Env, from: &Address, spender: &Address, amount: i128) -> Result<(), PermissionedTokenError> {
+    check_amount(amount)?;
+    let allowance_key = DataKey::Allowance(from.clone(), spender.clone());
+    let Some(mut allowance) = e.storage().temporary().get::<DataKey, AllowanceValue>(&allowance_key) else {
+        return Err(PermissionedTokenError::InsufficientAllowance);
+    };
+    if allowance.expiration_ledger < e.ledger().sequence() || allowance.amount < amount {
+        return Err(PermissionedTokenError::InsufficientAllowance);
+    }
+
+    allowance.amount -= amount;
+    e.storage().temporary().set(&allowance_key, &allowance);
+    Ok(())
+}
+
+/**
+ * Transfers tokens between two verified wallets which are not frozen, if the token is not paused, the
+ * tokens of the sender are not frozen and the compliance allows it.
+ */
+fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), PermissionedTokenError> {
+    check_amount(amount)?;
+    if is_paused(e) {
+        return Err(PermissionedTokenError::Paused);
+    }
+    if is_frozen(e, from) || is_frozen(e, to) {
+        return Err(PermissionedTokenError::FrozenWallet);
+    }
+    if balance(e, from) - frozen_tokens(e, from) < amount {
+        return Err(PermissionedTokenError::InsufficientBalance);
+    }
+    let identity_registry = identity_registry(e)?;
+    if !identity_registry.is_verified(from) || !identity_registry.is_verified(to) {
+        return Err(PermissionedTokenError::UnverifiedWallet);
+    }
+    if !compliance(e)?.can_transfer(from, to, &amount) {
+        return Err(PermissionedTokenError::NonCompliantTransfer);
+    }
+
+    move_tokens(e, from, to, amount)
+}
+
+/**
+ * Moves tokens between two wallets and reports the transfer to the compliance.
+ */
+fn move_tokens(e: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), PermissionedTokenError> {
+    let from_balance = balance(e, from);
+    if from_balance < amount {
+        return Err(PermissionedTokenError::InsufficientBalance);
+    }
+
+    set_balance(e, from, from_balance - amount);
+    set_balance(e, to, balance(e, to) + amount);
+    compliance(e)?.transferred(from, to, &amount);
+    events::transfer(e, from, to, amount);
+    Ok(())
+}
+
+/**
+ * Burns tokens of a wallet which is not frozen, whose tokens are not frozen.
+ */
+fn burn(e: &Env, from: &Address, amount: i128) -> Result<(), PermissionedTokenError> {
+    check_amount(amount)?;
+    if is_frozen(e, from) {
+        return Err(PermissionedTokenError::FrozenWallet);
+    }
+    if balance(e, from) - frozen_tokens(e, from) < amount {
+        return Err(PermissionedTokenError::InsufficientBalance);
+    }
+
+    burn_tokens(e, from, amount)?;
+    events::burn(e, from, amount);
+    Ok(())
+}
+
+/**
+ * Burns tokens of a wallet and reports the burn to the compliance.
+ */
+fn burn_tokens(e: &Env, from: &Address, amount: i128) -> Result<(), PermissionedTokenError> {
+    let from_balance = balance(e, from);
+    if from_balance < amount {
+        return Err(PermissionedTokenError::InsufficientBalance);
+    }
+
+    set_balance(e, from, from_balance - amount);
+    e.storage().instance().set(&DataKey::TotalSupply, &(total_supply(e) - amount));
+    compliance(e)?.destroyed(from, &amount);
+    Ok(())
+}
+
+/**
+ * Unfreezes the frozen tokens of `wallet` an agent spends beyond its tokens which are not frozen.
+ */
+fn unfreeze_spent_tokens(e: &Env, wallet: &Address, amount: i128) -> Result<(), PermissionedTokenError> {
+    let balance = balance(e, wallet);
+    if balance < amount {
+        return Err(PermissionedTokenError::InsufficientBalance);
+    }
+
+    let frozen_tokens = frozen_tokens(e, wallet);
+    let free_balance = balance - frozen_tokens;
+    if amount > free_balance {
+        let unfrozen_tokens = amount - free_balance;
+        set_frozen_tokens(e, wallet, frozen_tokens - unfrozen_tokens);
+        events::tokens_unfrozen(e, wallet, unfrozen_tokens);
+    }
+    Ok(())
+}
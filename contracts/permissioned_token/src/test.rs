@@ -0,0 +1,273 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{error::PermissionedTokenError, permissioned_token::{PermissionedToken, PermissionedTokenClient}};
+use claim_issuer::claim_issuer::ClaimIssuer;
+use claim_topics_registry::claim_topics_registry::{ClaimTopicsRegistry, ClaimTopicsRegistryClient};
+use compliance::compliance::{Compliance, ComplianceClient, ComplianceModule};
+use identity_registry::identity_registry::{IdentityRegistry, IdentityRegistryClient};
+use trusted_issuers_registry::trusted_issuers_registry::{TrustedIssuersRegistry, TrustedIssuersRegistryClient};
+use onchain_id::{
+    claim_topics_registry::SPECIFIC_KYC_STATUS, erc734::ED25519_KEY_TYPE, key_store::address_to_key,
+    testutils::{add_claim, last_event, register_claim_issuer, register_identity, Keypair},
+};
+use identity::identity::{Identity, IdentityClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, IntoVal, String, Symbol};
+
+// A permissioned token with its compliance and its identity registry, requiring a KYC status claim of a trusted
+// claim issuer, and an agent of both the token and the identity registry.
+struct Token<'a> {
+    token: PermissionedTokenClient<'a>,
+    compliance: ComplianceClient<'a>,
+    identity_registry: IdentityRegistryClient<'a>,
+    claim_issuer: Address,
+    claim_key_pair: Keypair,
+    agent: Address,
+}
+
+fn create_token(env: &Env) -> Token<'_> {
+    env.mock_all_auths();
+    // Each transfer verifies the claims of both wallets
+    env.budget().reset_unlimited();
+
+    let claim_topics_registry = ClaimTopicsRegistryClient::new(env, &env.register_contract(None, ClaimTopicsRegistry));
+    claim_topics_registry.initialize(&Address::generate(env));
+    claim_topics_registry.add_claim_topic(&SPECIFIC_KYC_STATUS, &String::from_str(env, "SPECIFIC_KYC_STATUS"), &String::from_str(env, "STRING"));
+
+    let (claim_issuer, _, claim_key_pair) = register_claim_issuer(env, ClaimIssuer);
+
+    let trusted_issuers_registry = TrustedIssuersRegistryClient::new(env, &env.register_contract(None, TrustedIssuersRegistry));
+    trusted_issuers_registry.initialize(&Address::generate(env));
+    trusted_issuers_registry.add_trusted_issuer(&claim_issuer, &vec![env, SPECIFIC_KYC_STATUS]);
+
+    let identity_registry = IdentityRegistryClient::new(env, &env.register_contract(None, IdentityRegistry));
+    identity_registry.initialize(&Address::generate(env), &claim_topics_registry.address, &trusted_issuers_registry.address);
+    let compliance = ComplianceClient::new(env, &env.register_contract(None, Compliance));
+    compliance.initialize(&Address::generate(env), &identity_registry.address);
+
+    let token = PermissionedTokenClient::new(env, &env.register_contract(None, PermissionedToken));
+    token.initialize(&Address::generate(env), &7, &String::from_str(env, "Security"), &String::from_str(env, "SEC"), &identity_registry.address, &compliance.address);
+    compliance.bind_token(&token.address);
+
+    let agent = Address::generate(env);
+    token.add_agent(&agent);
+    identity_registry.add_agent(&agent);
+    identity_registry.add_agent(&token.address);
+
+    Token { token, compliance, identity_registry, claim_issuer, claim_key_pair, agent }
+}
+
+// Registers an investor whose identity holds a KYC status claim, the wallet being the owner of the identity.
+fn create_investor<'a>(env: &'a Env, token: &Token<'_>) -> (Address, IdentityClient<'a>) {
+    let (identity, wallet) = register_identity(env, Identity);
+    add_claim(env, &identity, &wallet, &token.claim_issuer, &token.claim_key_pair, SPECIFIC_KYC_STATUS, 0, 0);
+
+    token.identity_registry.register_identity(&token.agent, &wallet, &identity, &250);
+    (wallet, IdentityClient::new(env, &identity))
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let Token { token, compliance, identity_registry, .. } = create_token(&env);
+
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.name(), String::from_str(&env, "Security"));
+    assert_eq!(token.symbol(), String::from_str(&env, "SEC"));
+    assert_eq!(token.get_identity_registry(), identity_registry.address);
+    assert_eq!(token.get_compliance(), compliance.address);
+    assert_eq!(
+        token.try_initialize(&Address::generate(&env), &7, &String::from_str(&env, "Security"), &String::from_str(&env, "SEC"), &identity_registry.address, &compliance.address),
+        Err(Ok(PermissionedTokenError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_mint() {
+    let env = Env::default();
+    let stack = create_token(&env);
+    let Token { token, compliance, agent, .. } = &stack;
+    let (wallet, identity) = create_investor(&env, &stack);
+
+    token.mint(agent, &wallet, &100);
+    assert_eq!(env.auths()[0].0, agent.clone());
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (token.address.clone(), (Symbol::new(&env, "mint"), agent.clone(), wallet.clone()).into_val(&env), 100_i128.into_val(&env))]
+    );
+    assert_eq!(token.balance(&wallet), 100);
+    assert_eq!(token.total_supply(), 100);
+    assert_eq!(compliance.get_identity_balance(&identity.address), 100);
+
+    assert_eq!(token.try_mint(&wallet, &wallet, &100), Err(Ok(PermissionedTokenError::Unauthorized)));
+    assert_eq!(token.try_mint(agent, &Address::generate(&env), &100), Err(Ok(PermissionedTokenError::UnverifiedWallet)));
+    compliance.add_module(&ComplianceModule::MaxBalance(150));
+    assert_eq!(token.try_mint(agent, &wallet, &100), Err(Ok(PermissionedTokenError::NonCompliantTransfer)));
+}
+
+#[test]
+fn test_transfer() {
+    let env = Env::default();
+    let stack = create_token(&env);
+    let Token { token, identity_registry, agent, .. } = &stack;
+    let (alice, _) = create_investor(&env, &stack);
+    let (bob, _) = create_investor(&env, &stack);
+    token.mint(agent, &alice, &100);
+
+    token.transfer(&alice, &bob, &40);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (token.address.clone(), (Symbol::new(&env, "transfer"), alice.clone(), bob.clone()).into_val(&env), 40_i128.into_val(&env))]
+    );
+    assert_eq!(token.balance(&alice), 60);
+    assert_eq!(token.balance(&bob), 40);
+    assert_eq!(token.try_transfer(&alice, &bob, &61), Err(Ok(PermissionedTokenError::InsufficientBalance.into())));
+
+    // Both wallets must be verified
+    assert_eq!(token.try_transfer(&alice, &Address::generate(&env), &10), Err(Ok(PermissionedTokenError::UnverifiedWallet.into())));
+    identity_registry.delete_identity(agent, &bob);
+    assert_eq!(token.try_transfer(&bob, &alice, &10), Err(Ok(PermissionedTokenError::UnverifiedWallet.into())));
+
+    // Spenders transfer up to their allowance
+    let spender = Address::generate(&env);
+    token.approve(&alice, &spender, &30, &1_000);
+    assert_eq!(token.allowance(&alice, &spender), 30);
+    let (carol, _) = create_investor(&env, &stack);
+    token.transfer_from(&spender, &alice, &carol, &20);
+    assert_eq!(token.balance(&carol), 20);
+    assert_eq!(token.allowance(&alice, &spender), 10);
+    assert_eq!(token.try_transfer_from(&spender, &alice, &carol, &20), Err(Ok(PermissionedTokenError::InsufficientAllowance.into())));
+}
+
+#[test]
+fn test_pause() {
+    let env = Env::default();
+    let stack = create_token(&env);
+    let Token { token, agent, .. } = &stack;
+    let (alice, _) = create_investor(&env, &stack);
+    let (bob, _) = create_investor(&env, &stack);
+    token.mint(agent, &alice, &100);
+
+    token.pause(agent);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (token.address.clone(), (Symbol::new(&env, "Paused"),).into_val(&env), agent.into_val(&env))]
+    );
+    assert!(token.is_paused());
+    assert_eq!(token.try_pause(agent), Err(Ok(PermissionedTokenError::Paused)));
+    assert_eq!(token.try_transfer(&alice, &bob, &10), Err(Ok(PermissionedTokenError::Paused.into())));
+
+    token.unpause(agent);
+    assert_eq!(token.try_unpause(agent), Err(Ok(PermissionedTokenError::NotPaused)));
+    token.transfer(&alice, &bob, &10);
+}
+
+#[test]
+fn test_freeze() {
+    let env = Env::default();
+    let stack = create_token(&env);
+    let Token { token, agent, .. } = &stack;
+    let (alice, _) = create_investor(&env, &stack);
+    let (bob, _) = create_investor(&env, &stack);
+    token.mint(agent, &alice, &100);
+
+    token.set_address_frozen(agent, &bob, &true);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (token.address.clone(), (Symbol::new(&env, "AddressFrozen"), bob.clone(), true).into_val(&env), agent.into_val(&env))]
+    );
+    assert!(token.is_frozen(&bob));
+    assert_eq!(token.try_transfer(&alice, &bob, &10), Err(Ok(PermissionedTokenError::FrozenWallet.into())));
+    token.set_address_frozen(agent, &bob, &false);
+
+    token.freeze_partial_tokens(agent, &alice, &70);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (token.address.clone(), (Symbol::new(&env, "TokensFrozen"), alice.clone()).into_val(&env), 70_i128.into_val(&env))]
+    );
+    assert_eq!(token.get_frozen_tokens(&alice), 70);
+    assert_eq!(token.try_freeze_partial_tokens(agent, &alice, &31), Err(Ok(PermissionedTokenError::InsufficientBalance)));
+    assert_eq!(token.try_transfer(&alice, &bob, &31), Err(Ok(PermissionedTokenError::InsufficientBalance.into())));
+    assert_eq!(token.try_burn(&alice, &31), Err(Ok(PermissionedTokenError::InsufficientBalance.into())));
+    token.transfer(&alice, &bob, &30);
+
+    token.unfreeze_partial_tokens(agent, &alice, &20);
+    assert_eq!(token.get_frozen_tokens(&alice), 50);
+    assert_eq!(token.try_unfreeze_partial_tokens(agent, &alice, &51), Err(Ok(PermissionedTokenError::InsufficientBalance)));
+}
+
+#[test]
+fn test_forced_transfer_and_clawback() {
+    let env = Env::default();
+    let stack = create_token(&env);
+    let Token { token, compliance, identity_registry, agent, .. } = &stack;
+    let (alice, _) = create_investor(&env, &stack);
+    let (bob, bob_identity) = create_investor(&env, &stack);
+    token.mint(agent, &alice, &100);
+    token.freeze_partial_tokens(agent, &alice, &80);
+    token.set_address_frozen(agent, &alice, &true);
+
+    // Agents move frozen tokens, which are unfrozen
+    token.forced_transfer(agent, &alice, &bob, &50);
+    assert_eq!(token.balance(&bob), 50);
+    assert_eq!(token.get_frozen_tokens(&alice), 50);
+    assert_eq!(token.try_forced_transfer(agent, &alice, &Address::generate(&env), &10), Err(Ok(PermissionedTokenError::UnverifiedWallet)));
+
+    token.clawback(agent, &alice, &50);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (token.address.clone(), (Symbol::new(&env, "clawback"), agent.clone(), alice.clone()).into_val(&env), 50_i128.into_val(&env))]
+    );
+    assert_eq!(token.balance(&alice), 0);
+    assert_eq!(token.get_frozen_tokens(&alice), 0);
+    assert_eq!(token.total_supply(), 50);
+    assert_eq!(compliance.get_holder_count(), 1);
+
+    // Holders burn their own tokens
+    token.burn(&bob, &20);
+    assert_eq!(token.balance(&bob), 30);
+    assert_eq!(token.total_supply(), 30);
+
+    // The tokens of a wallet deleted from the identity registry are still clawed back
+    identity_registry.delete_identity(agent, &bob);
+    token.clawback(agent, &bob, &30);
+    assert_eq!(token.balance(&bob), 0);
+    assert_eq!(compliance.get_identity_balance(&bob_identity.address), 0);
+    assert_eq!(compliance.get_holder_count(), 0);
+}
+
+#[test]
+fn test_recovery_address() {
+    let env = Env::default();
+    let stack = create_token(&env);
+    let Token { token, compliance, identity_registry, agent, .. } = &stack;
+    let (lost_wallet, identity) = create_investor(&env, &stack);
+    token.mint(agent, &lost_wallet, &100);
+    token.freeze_partial_tokens(agent, &lost_wallet, &30);
+
+    // The new wallet must hold a MANAGEMENT key of the identity
+    let new_wallet = Address::generate(&env);
+    assert_eq!(
+        token.try_recovery_address(agent, &lost_wallet, &new_wallet, &identity.address),
+        Err(Ok(PermissionedTokenError::WalletNotRecoverable))
+    );
+    identity.add_key(&lost_wallet, &address_to_key(&env, &new_wallet), &1, &ED25519_KEY_TYPE);
+    assert_eq!(
+        token.try_recovery_address(agent, &lost_wallet, &new_wallet, &Address::generate(&env)),
+        Err(Ok(PermissionedTokenError::WalletNotRecoverable))
+    );
+
+    token.recovery_address(agent, &lost_wallet, &new_wallet, &identity.address);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (token.address.clone(), (Symbol::new(&env, "RecoverySuccess"), lost_wallet.clone(), new_wallet.clone()).into_val(&env), identity.address.into_val(&env))]
+    );
+    assert_eq!(token.balance(&lost_wallet), 0);
+    assert_eq!(token.balance(&new_wallet), 100);
+    assert_eq!(token.get_frozen_tokens(&new_wallet), 30);
+    assert_eq!(identity_registry.get_identity(&new_wallet), Some(identity.address.clone()));
+    assert_eq!(identity_registry.get_investor_country(&new_wallet), Some(250));
+    assert!(!identity_registry.contains(&lost_wallet));
+    assert_eq!(compliance.get_identity_balance(&identity.address), 100);
+    assert!(identity_registry.is_verified(&new_wallet));
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TrustedIssuersRegistryError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    IssuerAlreadyTrusted = 3,
+    IssuerNotTrusted = 4,
+    NoClaimTopics = 5
+}
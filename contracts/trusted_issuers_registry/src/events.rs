@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+/**
+ * Emitted when a trusted issuer is added to the registry.
+ * Topics: ("TrustedIssuerAdded", issuer). Data: the claim topics of the issuer.
+ */
+pub(crate) fn trusted_issuer_added(e: &Env, issuer: &Address, claim_topics: &Vec<u128>) {
+    e.events().publish((Symbol::new(e, "TrustedIssuerAdded"), issuer.clone()), claim_topics.clone());
+}
+
+/**
+ * Emitted when a trusted issuer is removed from the registry.
+ * Topics: ("TrustedIssuerRemoved", issuer). Data: ().
+ */
+pub(crate) fn trusted_issuer_removed(e: &Env, issuer: &Address) {
+    e.events().publish((Symbol::new(e, "TrustedIssuerRemoved"), issuer.clone()), ());
+}
+
+/**
+ * Emitted when the claim topics of a trusted issuer are updated.
+ * Topics: ("ClaimTopicsUpdated", issuer). Data: the new claim topics of the issuer.
+ */
+pub(crate) fn claim_topics_updated(e: &Env, issuer: &Address, claim_topics: &Vec<u128>) {
+    e.events().publish((Symbol::new(e, "ClaimTopicsUpdated"), issuer.clone()), claim_topics.clone());
+}
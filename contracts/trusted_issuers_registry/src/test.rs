@@ -0,0 +1,111 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{error::TrustedIssuersRegistryError, trusted_issuers_registry::{TrustedIssuersRegistry, TrustedIssuersRegistryClient}};
+use onchain_id::{claim_topics_registry::{AML_STATUS, COUNTRY, SPECIFIC_KYC_STATUS}, testutils::last_event};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, IntoVal, Symbol};
+
+fn create_registry(env: &Env) -> TrustedIssuersRegistryClient<'_> {
+    env.mock_all_auths();
+    let client = TrustedIssuersRegistryClient::new(env, &env.register_contract(None, TrustedIssuersRegistry));
+    client.initialize(&Address::generate(env));
+    client
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let client = create_registry(&env);
+
+    assert_eq!(client.get_trusted_issuers(), vec![&env]);
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env)),
+        Err(Ok(TrustedIssuersRegistryError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_add_trusted_issuer() {
+    let env = Env::default();
+    let client = create_registry(&env);
+    let issuer = Address::generate(&env);
+    let claim_topics = vec![&env, COUNTRY, SPECIFIC_KYC_STATUS];
+
+    assert_eq!(
+        client.try_add_trusted_issuer(&issuer, &vec![&env]),
+        Err(Ok(TrustedIssuersRegistryError::NoClaimTopics))
+    );
+    client.add_trusted_issuer(&issuer, &claim_topics);
+    assert_eq!(env.auths()[0].0, client.get_admin());
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "TrustedIssuerAdded"), issuer.clone()).into_val(&env), claim_topics.into_val(&env))]
+    );
+    assert_eq!(
+        client.try_add_trusted_issuer(&issuer, &claim_topics),
+        Err(Ok(TrustedIssuersRegistryError::IssuerAlreadyTrusted))
+    );
+
+    assert!(client.is_trusted_issuer(&issuer));
+    assert!(!client.is_trusted_issuer(&Address::generate(&env)));
+    assert_eq!(client.get_trusted_issuers(), vec![&env, issuer.clone()]);
+    assert_eq!(client.get_trusted_issuer_claim_topics(&issuer), claim_topics);
+    assert!(client.has_claim_topic(&issuer, &COUNTRY));
+    assert!(!client.has_claim_topic(&issuer, &AML_STATUS));
+
+    let other_issuer = Address::generate(&env);
+    client.add_trusted_issuer(&other_issuer, &vec![&env, COUNTRY]);
+    assert_eq!(client.get_trusted_issuers_for_topic(&COUNTRY), vec![&env, issuer.clone(), other_issuer]);
+    assert_eq!(client.get_trusted_issuers_for_topic(&SPECIFIC_KYC_STATUS), vec![&env, issuer]);
+}
+
+#[test]
+fn test_update_issuer_claim_topics() {
+    let env = Env::default();
+    let client = create_registry(&env);
+    let issuer = Address::generate(&env);
+    client.add_trusted_issuer(&issuer, &vec![&env, COUNTRY, SPECIFIC_KYC_STATUS]);
+
+    let claim_topics = vec![&env, COUNTRY, AML_STATUS];
+    client.update_issuer_claim_topics(&issuer, &claim_topics);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "ClaimTopicsUpdated"), issuer.clone()).into_val(&env), claim_topics.into_val(&env))]
+    );
+    assert_eq!(client.get_trusted_issuer_claim_topics(&issuer), claim_topics);
+    assert!(!client.has_claim_topic(&issuer, &SPECIFIC_KYC_STATUS));
+    assert!(client.has_claim_topic(&issuer, &AML_STATUS));
+    assert_eq!(client.get_trusted_issuers_for_topic(&SPECIFIC_KYC_STATUS), vec![&env]);
+    assert_eq!(client.get_trusted_issuers_for_topic(&COUNTRY), vec![&env, issuer.clone()]);
+
+    assert_eq!(
+        client.try_update_issuer_claim_topics(&issuer, &vec![&env]),
+        Err(Ok(TrustedIssuersRegistryError::NoClaimTopics))
+    );
+    assert_eq!(
+        client.try_update_issuer_claim_topics(&Address::generate(&env), &claim_topics),
+        Err(Ok(TrustedIssuersRegistryError::IssuerNotTrusted))
+    );
+}
+
+#[test]
+fn test_remove_trusted_issuer() {
+    let env = Env::default();
+    let client = create_registry(&env);
+    let issuer = Address::generate(&env);
+    client.add_trusted_issuer(&issuer, &vec![&env, COUNTRY]);
+
+    client.remove_trusted_issuer(&issuer);
+    assert_eq!(
+        last_event(&env),
+        vec![&env, (client.address.clone(), (Symbol::new(&env, "TrustedIssuerRemoved"), issuer.clone()).into_val(&env), ().into_val(&env))]
+    );
+    assert!(!client.is_trusted_issuer(&issuer));
+    assert!(!client.has_claim_topic(&issuer, &COUNTRY));
+    assert_eq!(client.get_trusted_issuers(), vec![&env]);
+    assert_eq!(client.get_trusted_issuers_for_topic(&COUNTRY), vec![&env]);
+    assert_eq!(
+        client.try_remove_trusted_issuer(&issuer),
+        Err(Ok(TrustedIssuersRegistryError::IssuerNotTrusted))
+    );
+}
@@ -0,0 +1,200 @@
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Env, Vec};
+use onchain_id::{storage::{extend_instance, get_persistent, set_persistent}, trusted_issuers_registry::ITrustedIssuersRegistry};
+
+use crate::{error::TrustedIssuersRegistryError, events};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    TrustedIssuers,
+    IssuerClaimTopics(Address),
+    ClaimTopicIssuers(u128),
+}
+
+/**
+ * Registry of the claim issuers trusted by the verifiers, mirroring the TrustedIssuersRegistry contract
+ * of T-REX. Each trusted issuer is registered with the claim topics it may attest, so a claim is only
+ * trusted if its issuer is registered for the topic of the claim.
+ */
+#[contract]
+pub struct TrustedIssuersRegistry;
+
+#[contractimpl]
+impl TrustedIssuersRegistry {
+    /**
+    * Initializes the registry with its admin and no trusted issuer.
+    * Can only be called once, right after the deployment of the registry.
+    * @param _admin address managing the trusted issuers, which must authorize the initialization
+    */
+    pub fn initialize(e: Env, admin: Address) -> Result<(), TrustedIssuersRegistryError> {
+        if e.storage().instance().has(&DataKey::Admin) {
+            return Err(TrustedIssuersRegistryError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::TrustedIssuers, &Vec::<Address>::new(&e));
+        extend_instance(&e);
+        Ok(())
+    }
+
+    /**
+    * Adds a trusted issuer with the claim topics it may attest.
+    * MUST only be done by the admin of the registry.
+    * @param _issuer the address of the claim issuer contract, which must not be trusted yet
+    * @param _claimTopics the claim topics the issuer may attest, at least one
+    */
+    pub fn add_trusted_issuer(e: Env, issuer: Address, claim_topics: Vec<u128>) -> Result<(), TrustedIssuersRegistryError> {
+        get_admin(&e)?.require_auth();
+
+        let mut trusted_issuers = get_trusted_issuers(&e)?;
+        if trusted_issuers.contains(&issuer) {
+            return Err(TrustedIssuersRegistryError::IssuerAlreadyTrusted);
+        }
+        if claim_topics.is_empty() {
+            return Err(TrustedIssuersRegistryError::NoClaimTopics);
+        }
+
+        trusted_issuers.push_back(issuer.clone());
+        e.storage().instance().set(&DataKey::TrustedIssuers, &trusted_issuers);
+        extend_instance(&e);
+        set_claim_topics(&e, &issuer, &claim_topics);
+        events::trusted_issuer_added(&e, &issuer, &claim_topics);
+        Ok(())
+    }
+
+    /**
+    * Replaces the claim topics a trusted issuer may attest.
+    * MUST only be done by the admin of the registry.
+    * @param _issuer the address of the trusted issuer
+    * @param _claimTopics the claim topics the issuer may attest, at least one
+    */
+    pub fn update_issuer_claim_topics(e: Env, issuer: Address, claim_topics: Vec<u128>) -> Result<(), TrustedIssuersRegistryError> {
+        get_admin(&e)?.require_auth();
+
+        if !get_trusted_issuers(&e)?.contains(&issuer) {
+            return Err(TrustedIssuersRegistryError::IssuerNotTrusted);
+        }
+        if claim_topics.is_empty() {
+            return Err(TrustedIssuersRegistryError::NoClaimTopics);
+        }
+
+        remove_claim_topics(&e, &issuer);
+        set_claim_topics(&e, &issuer, &claim_topics);
+        events::claim_topics_updated(&e, &issuer, &claim_topics);
+        Ok(())
+    }
+
+    /**
+    * Removes a trusted issuer, whose claims are then no longer trusted.
+    * MUST only be done by the admin of the registry.
+    * @param _issuer the address of the trusted issuer
+    */
+    pub fn remove_trusted_issuer(e: Env, issuer: Address) -> Result<(), TrustedIssuersRegistryError> {
+        get_admin(&e)?.require_auth();
+
+        let mut trusted_issuers = get_trusted_issuers(&e)?;
+        let issuer_index = trusted_issuers.first_index_of(&issuer).ok_or(TrustedIssuersRegistryError::IssuerNotTrusted)?;
+
+        trusted_issuers.remove(issuer_index);
+        e.storage().instance().set(&DataKey::TrustedIssuers, &trusted_issuers);
+        extend_instance(&e);
+        remove_claim_topics(&e, &issuer);
+        events::trusted_issuer_removed(&e, &issuer);
+        Ok(())
+    }
+
+    /**
+    * Returns the admin of the registry.
+    */
+    pub fn get_admin(e: Env) -> Result<Address, TrustedIssuersRegistryError> {
+        get_admin(&e)
+    }
+}
+
+#[contractimpl]
+impl ITrustedIssuersRegistry for TrustedIssuersRegistry {
+    /**
+     * See {ITrustedIssuersRegistry-get_trusted_issuers}.
+     */
+    fn get_trusted_issuers(e: Env) -> Vec<Address> {
+        get_trusted_issuers(&e).unwrap_or(vec![&e])
+    }
+
+    /**
+     * See {ITrustedIssuersRegistry-is_trusted_issuer}.
+     */
+    fn is_trusted_issuer(e: Env, issuer: Address) -> bool {
+        e.storage().persistent().has(&DataKey::IssuerClaimTopics(issuer))
+    }
+
+    /**
+     * See {ITrustedIssuersRegistry-get_trusted_issuer_claim_topics}.
+     */
+    fn get_trusted_issuer_claim_topics(e: Env, issuer: Address) -> Vec<u128> {
+        get_persistent(&e, &DataKey::IssuerClaimTopics(issuer)).unwrap_or(vec![&e])
+    }
+
+    /**
+     * See {ITrustedIssuersRegistry-get_trusted_issuers_for_topic}.
+     */
+    fn get_trusted_issuers_for_topic(e: Env, topic: u128) -> Vec<Address> {
+        get_persistent(&e, &DataKey::ClaimTopicIssuers(topic)).unwrap_or(vec![&e])
+    }
+
+    /**
+     * See {ITrustedIssuersRegistry-has_claim_topic}.
+     */
+    fn has_claim_topic(e: Env, issuer: Address, topic: u128) -> bool {
+        get_persistent::<Vec<u128>>(&e, &DataKey::IssuerClaimTopics(issuer)).is_some_and(|claim_topics| claim_topics.contains(topic))
+    }
+}
+
+fn get_admin(e: &Env) -> Result<Address, TrustedIssuersRegistryError> {
+    e.storage().instance().get(&DataKey::Admin).ok_or(TrustedIssuersRegistryError::NotInitialized)
+}
+
+fn get_trusted_issuers(e: &Env) -> Result<Vec<Address>, TrustedIssuersRegistryError> {
+    e.storage().instance().get(&DataKey::TrustedIssuers).ok_or(TrustedIssuersRegistryError::NotInitialized)
+}
+
+/**
+ * Records the claim topics of `issuer`, and the issuer in the trusted issuers of each topic.
+ */
+fn set_claim_topics(e: &Env, issuer: &Address, claim_topics: &Vec<u128>) {
+    let mut issuer_claim_topics: Vec<u128> = vec![e];
+    for topic in claim_topics.iter() {
+        if issuer_claim_topics.contains(topic) {
+            continue;
+        }
+        issuer_claim_topics.push_back(topic);
+
+        let topic_issuers_key = DataKey::ClaimTopicIssuers(topic);
+        let mut topic_issuers: Vec<Address> = get_persistent(e, &topic_issuers_key).unwrap_or(vec![e]);
+        topic_issuers.push_back(issuer.clone());
+        set_persistent(e, &topic_issuers_key, &topic_issuers);
+    }
+    set_persistent(e, &DataKey::IssuerClaimTopics(issuer.clone()), &issuer_claim_topics);
+}
+
+/**
+ * Removes the claim topics of `issuer`, and the issuer from the trusted issuers of each topic.
+ */
+fn remove_claim_topics(e: &Env, issuer: &Address) {
+    let issuer_claim_topics_key = DataKey::IssuerClaimTopics(issuer.clone());
+    let issuer_claim_topics: Vec<u128> = get_persistent(e, &issuer_claim_topics_key).unwrap_or(vec![e]);
+    for topic in issuer_claim_topics.iter() {
+        let topic_issuers_key = DataKey::ClaimTopicIssuers(topic);
+        let mut topic_issuers: Vec<Address> = get_persistent(e, &topic_issuers_key).unwrap_or(vec![e]);
+        if let Some(issuer_index) = topic_issuers.first_index_of(issuer) {
+            topic_issuers.remove(issuer_index);
+        }
+        if topic_issuers.is_empty() {
+            e.storage().persistent().remove(&topic_issuers_key);
+        } else {
+            set_persistent(e, &topic_issuers_key, &topic_issuers);
+        }
+    }
+    e.storage().persistent().remove(&issuer_claim_topics_key);
+}
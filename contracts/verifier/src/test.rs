@@ -0,0 +1,86 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::verifier::{Verifier, VerifierClient};
+use claim_issuer::claim_issuer::{ClaimIssuer, ClaimIssuerClient};
+use onchain_id::{
+    claim_topics_registry::{AML_STATUS, COUNTRY, SPECIFIC_ACCREDITATION, SPECIFIC_KYC_STATUS},
+    structs::{Claim, ClaimStatus, DataKey, TopicVerification, Verification},
+    testutils::{add_claim, register_claim_issuer, register_identity},
+};
+use identity::identity::Identity;
+use soroban_sdk::{testutils::Ledger, vec, Bytes, BytesN, Env, String};
+
+#[test]
+fn test_verify() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let verifier = VerifierClient::new(&env, &env.register_contract(None, Verifier));
+    let (issuer, issuer_owner, key_pair) = register_claim_issuer(&env, ClaimIssuer);
+    let issuer = ClaimIssuerClient::new(&env, &issuer);
+    let (untrusted_issuer, _, untrusted_key_pair) = register_claim_issuer(&env, ClaimIssuer);
+    let (identity, owner) = register_identity(&env, Identity);
+
+    add_claim(&env, &identity, &owner, &issuer.address, &key_pair, SPECIFIC_KYC_STATUS, 0, 0);
+    add_claim(&env, &identity, &owner, &untrusted_issuer, &untrusted_key_pair, COUNTRY, 0, 0);
+    add_claim(&env, &identity, &owner, &issuer.address, &key_pair, AML_STATUS, 0, 1_000);
+    let revoked_signature = add_claim(&env, &identity, &owner, &issuer.address, &key_pair, SPECIFIC_ACCREDITATION, 0, 0);
+    issuer.revoke_claim_by_signature(&issuer_owner, &revoked_signature);
+    env.ledger().set_timestamp(1_001);
+
+    // A claim stored ahead of its validity window, as other identity implementations may hold
+    env.as_contract(&identity, || {
+        let claim_id = BytesN::from_array(&env, &[2; 32]);
+        let claim = Claim { topic: 2, scheme: 1, issuer: issuer.address.clone(), signature: Bytes::new(&env), data: Bytes::new(&env), uri: String::from_str(&env, ""), valid_from: 2_000, valid_until: 0 };
+        env.storage().persistent().set(&DataKey::Claim(claim_id.clone()), &claim);
+        env.storage().persistent().set(&DataKey::ClaimTopic(2), &vec![&env, claim_id]);
+    });
+
+    let trusted_issuers = vec![&env, issuer.address.clone()];
+    assert!(verifier.is_verified(&identity, &vec![&env, SPECIFIC_KYC_STATUS], &trusted_issuers));
+    assert_eq!(
+        verifier.verify(&identity, &vec![&env, SPECIFIC_KYC_STATUS, COUNTRY, AML_STATUS, SPECIFIC_ACCREDITATION, 1, 2], &trusted_issuers),
+        Verification {
+            verified: false,
+            topics: vec![
+                &env,
+                TopicVerification { topic: SPECIFIC_KYC_STATUS, status: ClaimStatus::Valid },
+                TopicVerification { topic: COUNTRY, status: ClaimStatus::UnknownIssuer },
+                TopicVerification { topic: AML_STATUS, status: ClaimStatus::Expired },
+                TopicVerification { topic: SPECIFIC_ACCREDITATION, status: ClaimStatus::Revoked },
+                TopicVerification { topic: 1, status: ClaimStatus::NoClaim },
+                TopicVerification { topic: 2, status: ClaimStatus::NotYetValid },
+            ],
+        }
+    );
+
+    // Without any required topic, every identity is verified
+    assert_eq!(verifier.verify(&identity, &vec![&env], &trusted_issuers), Verification { verified: true, topics: vec![&env] });
+}
+
+#[test]
+fn test_verify_topic_with_several_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let verifier = VerifierClient::new(&env, &env.register_contract(None, Verifier));
+    let (issuer, issuer_owner, key_pair) = register_claim_issuer(&env, ClaimIssuer);
+    let issuer = ClaimIssuerClient::new(&env, &issuer);
+    let (other_issuer, _, other_key_pair) = register_claim_issuer(&env, ClaimIssuer);
+    let (identity, owner) = register_identity(&env, Identity);
+    let topics = vec![&env, SPECIFIC_KYC_STATUS];
+
+    add_claim(&env, &identity, &owner, &issuer.address, &key_pair, SPECIFIC_KYC_STATUS, 0, 0);
+    add_claim(&env, &identity, &owner, &other_issuer, &other_key_pair, SPECIFIC_KYC_STATUS, 0, 0);
+
+    // A valid claim of any trusted issuer verifies the topic
+    assert!(verifier.is_verified(&identity, &topics, &vec![&env, other_issuer.clone()]));
+    assert!(verifier.is_verified(&identity, &topics, &vec![&env, issuer.address.clone(), other_issuer.clone()]));
+    assert!(!verifier.is_verified(&identity, &topics, &vec![&env]));
+
+    // The claims of trusted issuers are reported rather than those of unknown issuers
+    issuer.remove_key(&issuer_owner, &BytesN::from_array(&env, key_pair.public.as_bytes()), &3);
+    assert_eq!(
+        verifier.verify(&identity, &topics, &vec![&env, issuer.address.clone()]).topics,
+        vec![&env, TopicVerification { topic: SPECIFIC_KYC_STATUS, status: ClaimStatus::Invalid }]
+    );
+}
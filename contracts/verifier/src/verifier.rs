@@ -0,0 +1,35 @@
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use onchain_id::{structs::Verification, verifier};
+
+/**
+ * Verifier of the claims of the identities, so the consumers of the identities do not repeat the
+ * verification of the claims of each required topic. The verification is also available to contracts
+ * as the onchain_id::verifier module.
+ */
+#[contract]
+pub struct Verifier;
+
+#[contractimpl]
+impl Verifier {
+    /**
+    * Verifies that `identity` holds a valid claim of each of `required_topics` issued by one of `trusted_issuers`.
+    * See {verifier-verify_topic} for the outcome reported for each topic.
+    * @param _identity the identity contract to verify
+    * @param _requiredTopics the required claim topics
+    * @param _trustedIssuers the claim issuer contracts trusted for every required topic
+    * @return verification Returns whether the identity is verified, with the outcome for each topic
+    */
+    pub fn verify(e: Env, identity: Address, required_topics: Vec<u128>, trusted_issuers: Vec<Address>) -> Verification {
+        verifier::verify(&e, &identity, &required_topics, &trusted_issuers)
+    }
+
+    /**
+    * Returns TRUE if `identity` holds a valid claim of each of `required_topics` issued by one of `trusted_issuers`.
+    * @param _identity the identity contract to verify
+    * @param _requiredTopics the required claim topics
+    * @param _trustedIssuers the claim issuer contracts trusted for every required topic
+    */
+    pub fn is_verified(e: Env, identity: Address, required_topics: Vec<u128>, trusted_issuers: Vec<Address>) -> bool {
+        verifier::verify(&e, &identity, &required_topics, &trusted_issuers).verified
+    }
+}
@@ -4,9 +4,13 @@ const { Networks } = require("@stellar/stellar-sdk");
 const rpcUrl = process.env.PUBLIC_SOROBAN_RPC_URL ?? "https://soroban-testnet.stellar.org";
 const networkPassphrase = Networks.TESTNET;
 const wasmHash = process.env.PUBLIC_SOROBAN_WASM_HASH;
+const claimIssuerWasmHash = process.env.PUBLIC_SOROBAN_CLAIM_ISSUER_WASM_HASH;
+const idFactoryWasmHash = process.env.PUBLIC_SOROBAN_ID_FACTORY_WASM_HASH;
 
 module.exports = {
     rpcUrl,
     networkPassphrase,
-    wasmHash
+    wasmHash,
+    claimIssuerWasmHash,
+    idFactoryWasmHash
 };
@@ -1,20 +1,20 @@
 const ClaimTopic = {
-    SPECIFIC_KYC_STATUS: 1010101,
-    SPECIFIC_ACCREDITATION: 1010102,
-    FIRST_NAME_CLEAR: 1000042003,
-    LAST_NAME_CLEAR: 1000042004,
-    INDIVIDUAL_INVESTOR: 1000100000,
-    BASIC_IDENTITY: 1000100001,
-    CONTACT_ADDRESS: 1000100002,
-    SOVEREIGN_IDENTITY: 1000100003,
-    POLITICALLY_EXPOSED: 1000100004,
-    INSTITUTIONAL_INVESTOR: 1000200000,
-    INSTITUTION_INFORMATION: 1000200001,
-    INSTITUTION_DETAILS: 1000200002,
-    LEGAL_IDENTIFICATION: 1000200003,
-    INSTITUTIONAL_ROLE: 1000200004,
-    COUNTRY: 1000300002,
-    AML_STATUS: 1000300003,
+    SPECIFIC_KYC_STATUS: 1010101n,
+    SPECIFIC_ACCREDITATION: 1010102n,
+    FIRST_NAME_CLEAR: 10101000042003n,
+    LAST_NAME_CLEAR: 10101000042004n,
+    INDIVIDUAL_INVESTOR: 10101000100000n,
+    BASIC_IDENTITY: 10101000100001n,
+    CONTACT_ADDRESS: 10101000100002n,
+    SOVEREIGN_IDENTITY: 10101000100003n,
+    POLITICALLY_EXPOSED: 10101000100004n,
+    INSTITUTIONAL_INVESTOR: 10101000200000n,
+    INSTITUTION_INFORMATION: 10101000200001n,
+    INSTITUTION_DETAILS: 10101000200002n,
+    LEGAL_IDENTIFICATION: 10101000200003n,
+    INSTITUTIONAL_ROLE: 10101000200004n,
+    COUNTRY: 10101000300002n,
+    AML_STATUS: 10101000300003n,
 };
 
 const KeyPurpose = {
@@ -26,7 +26,9 @@ const KeyPurpose = {
 
 const keyType = {
     ECDSA: 1,
-    RSA: 2
+    RSA: 2,
+    ED25519: 3,
+    SECP256R1: 4
 }
 
 module.exports = {
@@ -1,64 +1,139 @@
-const { Address } = require("@stellar/stellar-sdk");
+const { randomBytes } = require("crypto");
+const { Address, Keypair, nativeToScVal } = require("@stellar/stellar-sdk");
   const { Client, basicNodeSigner } = require("@stellar/stellar-sdk/contract");
-  const { rpcUrl, networkPassphrase, wasmHash } = require("./contracts/config.js");
+  const { rpcUrl, networkPassphrase, wasmHash, claimIssuerWasmHash, idFactoryWasmHash } = require("./contracts/config.js");
   const { generateFundedKeypair } = require("./contracts/util.js");
   const { ClaimTopic, KeyPurpose, keyType } = require("./contracts/constants.js");
 
   (async () => {
     console.log("Generating User Alice's key pair");
-    const aliceKeypair = await generateFundedKeypair();      // User's key which is used to deploy the identity contract
+    const aliceKeypair = await generateFundedKeypair();      // User's key which is the management key of the identity contract
     console.log(`User public key: ${aliceKeypair.publicKey()}`);
 
+    console.log('Generating Identity Factory owner key pair');
+    const factoryOwnerKeypair = await generateFundedKeypair();    // The key of the operator deploying the identities of the users
+    console.log(`Identity Factory owner public key: ${factoryOwnerKeypair.publicKey()}`);
+
     console.log('Generating Issuer key pair');
     const issuerKeypair = await generateFundedKeypair();    // The key of the issuer which will be used to sign claims
     console.log(`Issuer public key: ${issuerKeypair.publicKey()}`);
 
-    
 
 
     //////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-    // The below section demonstrates the deployment of an identity contract by a user Alice.
-    // Alice also adds a claim signer key to the contract which authorizes the issuer to sign claims about Alice
+    // The below section demonstrates the deployment of a claim issuer contract by the issuer.
+    // The key of the issuer is registered as its management key, which also allows it to sign claims.
 
     //////////////////////////////////////////////////////////////////////////////////////////////////////////////////
     console.log('\n///////////////////////////////////////////////////////////////');
-    console.log('Excecution on behalf of Alice');
+    console.log('Excecution on behalf of Issuer');
     console.log('///////////////////////////////////////////////////////////////');
-    console.log('\nDeploying Identity Contract');
+    console.log('\nDeploying Claim Issuer Contract');
 
-    const { signTransaction } = basicNodeSigner(aliceKeypair, networkPassphrase);
-    //Deploy the identity contract
-    const deployTx = await Client.deploy(
+    //Only the account deploying the claim issuer can initialize it, with the salt of the deployment
+    const issuerSalt = randomBytes(32);
+    const { signTransaction: issuerSignTransaction } = basicNodeSigner(issuerKeypair, networkPassphrase);
+    const deployIssuerTx = await Client.deploy(
+        null,
+        {
+          networkPassphrase: networkPassphrase,
+          rpcUrl,
+          wasmHash: claimIssuerWasmHash,
+          salt: issuerSalt,
+          publicKey: issuerKeypair.publicKey(),
+          signTransaction: issuerSignTransaction,
+        }
+      );
+    const { result: issuerClient } = await deployIssuerTx.signAndSend();
+    const issuerContractId = issuerClient.options.contractId;
+    console.log(`Claim Issuer Contract Address: ${issuerContractId}`);
+
+    const initializeIssuerTx = await issuerClient.initialize({
+        owner_key: issuerKeypair.rawPublicKey(),
+        owner_address: issuerKeypair.publicKey(),
+        salt: issuerSalt,
+      });
+    await initializeIssuerTx.signAndSend();
+
+
+    //////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+    // The below section demonstrates the deployment of an identity contract for a user Alice by the identity factory.
+    // The factory deploys the identity with a salt chosen by its owner and registers Alice's key as its management key.
+
+    //////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+    console.log('\n///////////////////////////////////////////////////////////////');
+    console.log('Excecution on behalf of the Identity Factory owner');
+    console.log('///////////////////////////////////////////////////////////////');
+    console.log('\nDeploying Identity Factory Contract');
+
+    const { signTransaction: factoryOwnerSignTransaction } = basicNodeSigner(factoryOwnerKeypair, networkPassphrase);
+    const deployFactoryTx = await Client.deploy(
         null,
         {
           networkPassphrase: networkPassphrase,
           rpcUrl,
-          wasmHash,
-          publicKey: aliceKeypair.publicKey(),
-          signTransaction,
+          wasmHash: idFactoryWasmHash,
+          publicKey: factoryOwnerKeypair.publicKey(),
+          signTransaction: factoryOwnerSignTransaction,
         }
       );
-    const { result: client } = await deployTx.signAndSend();
-    const identityContractId = client.options.contractId;
+    const { result: factoryClient } = await deployFactoryTx.signAndSend();
+    console.log(`Identity Factory Contract Address: ${factoryClient.options.contractId}`);
+
+    //Initialize the factory with the wasm hash of the identity contract it deploys
+    const initializeFactoryTx = await factoryClient.initialize({
+        owner: factoryOwnerKeypair.publicKey(),
+        identity_wasm_hash: Buffer.from(wasmHash, 'hex'),
+      });
+    await initializeFactoryTx.signAndSend();
+
+    //Deploy the identity of Alice, with Alice's key as the management key - purpose 1
+    console.log('\nDeploying Identity Contract of Alice');
+    const createIdentityTx = await factoryClient.create_identity({
+        wallet: aliceKeypair.publicKey(),
+        salt: aliceKeypair.publicKey(),
+      });
+    const { result: identityContractId } = await createIdentityTx.signAndSend();
     const identityAddress = new Address(identityContractId)
     console.log(`Identity Contract Address: ${identityContractId}`);
 
-    //Add issuer key with purpose as claim signer - purpose 3. Key type 1 is ECDSA
-    console.log('\nAdding claim signer key');
-    const addKeyTx = await client.add_key({
-        key: issuerKeypair.rawPublicKey(),
-        purpose: KeyPurpose.CLAIM_SIGNER,
-        key_type: keyType.ECDSA,
+    console.log('\n///////////////////////////////////////////////////////////////');
+    console.log('Excecution on behalf of Alice');
+    console.log('///////////////////////////////////////////////////////////////');
+
+    const { signTransaction } = basicNodeSigner(aliceKeypair, networkPassphrase);
+    const client = await Client.from({
+        contractId: identityContractId,
+        networkPassphrase: networkPassphrase,
+        rpcUrl,
+        publicKey: aliceKeypair.publicKey(),
+        signTransaction,
+      });
+
+    //Add an ACTION key for a device of Alice through the ERC-734 execution workflow. An execution names the
+    //function to invoke on the target contract and its arguments, fn_name and args, rather than the value and
+    //data of an Ethereum call. It is performed right away as Alice holds a MANAGEMENT key of the identity.
+    console.log('\nAdding an ACTION key for a device of Alice with execute');
+    const deviceKeypair = Keypair.random();
+    const executeTx = await client.execute({
+        caller: aliceKeypair.publicKey(),
+        to: identityContractId,
+        fn_name: 'add_key',
+        args: [
+          nativeToScVal(deviceKeypair.rawPublicKey(), { type: 'bytes' }),
+          nativeToScVal(KeyPurpose.ACTION, { type: 'u32' }),
+          nativeToScVal(keyType.ED25519, { type: 'u32' }),
+        ],
       });
-    const { result } = await addKeyTx.signAndSend()
-    console.log(`Result of adding key=${JSON.stringify(result)}`);
+    const executeResult = await executeTx.signAndSend();
+    console.log(`Execution id=${executeResult.result.value}`);
 
 
 
 
     //////////////////////////////////////////////////////////////////////////////////////////////////////////////////
     // The below section demonstrates the issuer adding a claim to the identity contract of Alice.
-    // The issuer signs the claim using the key added by Alice in the previous step which authorized the issuer to do so.
+    // The issuer signs the claim with a key of its claim issuer contract, which the identity calls to validate the claim.
     // Typically, the issuer would have their own app which would have validated the identity of Alice before adding the claim.
 
     //////////////////////////////////////////////////////////////////////////////////////////////////////////////////  
@@ -68,25 +143,29 @@ const { Address } = require("@stellar/stellar-sdk");
     console.log('///////////////////////////////////////////////////////////////');
     //We will be adding a claim that kyc check has been performed and it has passed.
     const claimTopic = ClaimTopic.SPECIFIC_KYC_STATUS;
-    const claimTopicBuffer = Buffer.allocUnsafe(4);
-    claimTopicBuffer.writeUInt32BE(claimTopic); 
+    const claimTopicBuffer = Buffer.alloc(16);
+    claimTopicBuffer.writeBigUInt64BE(claimTopic, 8);
     const data = "true";
 
-    //Calculate the signature of concatenated identity contract address, claim topic and data
+    //Calculate the signature of concatenated identity contract address, claim topic, validity window and data, as returned by claim_message
     let sigData = identityAddress.toBuffer();
     sigData = Buffer.concat([sigData, claimTopicBuffer]);
+    sigData = Buffer.concat([sigData, Buffer.alloc(16)]); //no validity window: valid_from = valid_until = 0
     sigData = Buffer.concat([sigData, Buffer.from(data)]);
-    const sig = issuerKeypair.sign(sigData);
+    const sig = Buffer.concat([issuerKeypair.rawPublicKey(), issuerKeypair.sign(sigData)]); //prefixed with the signing key
 
     //Add claim
     console.log('\nAdding KYC claim for user');
     const addClaimTx = await client.add_claim({
+        caller: aliceKeypair.publicKey(),
         topic: claimTopic,
         scheme: 1,
-        issuer: issuerKeypair.rawPublicKey(),
+        issuer: issuerContractId,
         signature: sig,
         data: Buffer.from(data),
         uri: '',
+        valid_from: 0,
+        valid_until: 0,
     });
     const addClaimResult = await addClaimTx.signAndSend()
     console.log(`Kyc Claim id=${JSON.stringify(addClaimResult.result)}`);
@@ -94,25 +173,29 @@ const { Address } = require("@stellar/stellar-sdk");
 
     //We will be adding a claim that kyc check has been performed and it has passed.
     const claimTopicName = ClaimTopic.FIRST_NAME_CLEAR;
-    const claimTopicNameBuffer = Buffer.allocUnsafe(4);
-    claimTopicNameBuffer.writeUInt32BE(claimTopicName); 
+    const claimTopicNameBuffer = Buffer.alloc(16);
+    claimTopicNameBuffer.writeBigUInt64BE(claimTopicName, 8);
     const nameData = "Alice";
 
-    //Calculate the signature of concatenated identity contract address, claim topic and data
+    //Calculate the signature of concatenated identity contract address, claim topic, validity window and data, as returned by claim_message
     let sigNameData = identityAddress.toBuffer();
     sigNameData = Buffer.concat([sigNameData, claimTopicNameBuffer]);
+    sigNameData = Buffer.concat([sigNameData, Buffer.alloc(16)]); //no validity window: valid_from = valid_until = 0
     sigNameData = Buffer.concat([sigNameData, Buffer.from(nameData)]);
-    const nameSig = issuerKeypair.sign(sigNameData);
+    const nameSig = Buffer.concat([issuerKeypair.rawPublicKey(), issuerKeypair.sign(sigNameData)]); //prefixed with the signing key
 
     //Add claim
     console.log('\nAdding Name claim for user');
     const addNameClaimTx = await client.add_claim({
+        caller: aliceKeypair.publicKey(),
         topic: claimTopicName,
         scheme: 1,
-        issuer: issuerKeypair.rawPublicKey(),
+        issuer: issuerContractId,
         signature: nameSig,
         data: Buffer.from(nameData),
         uri: '',
+        valid_from: 0,
+        valid_until: 0,
     });
     const addNameClaimResult = await addNameClaimTx.signAndSend()
     console.log(`Name Claim id=${JSON.stringify(addNameClaimResult.result)}`);
@@ -140,7 +223,7 @@ const { Address } = require("@stellar/stellar-sdk");
 
     const [gotTopic, gotScheme, gotIssuer, gotSig, gotData, gotUri] = getClaimTx.result.value;
     console.log(`Retrieved Kyc Claim:`);
-    console.log(`Topic=${gotTopic}, Scheme=${gotScheme}, Issuer=${Address.account(gotIssuer).toString()}, Signature=${gotSig}, Data=${gotData.toString()}, Uri=${gotUri}`);
+    console.log(`Topic=${gotTopic}, Scheme=${gotScheme}, Issuer=${gotIssuer}, Signature=${gotSig}, Data=${gotData.toString()}, Uri=${gotUri}`);
 
     //Retreive Name claim of user from the identity contract
     console.log('\nRetrieve user Name claim from contract');
@@ -150,17 +233,18 @@ const { Address } = require("@stellar/stellar-sdk");
 
     const [gotNameTopic, gotNameScheme, gotNameIssuer, gotNameSig, gotNameData, gotNameUri] = getNameClaimTx.result.value;
     console.log(`Retrieved Name Claim:`);
-    console.log(`Topic=${gotNameTopic}, Scheme=${gotNameScheme}, Issuer=${Address.account(gotNameIssuer).toString()}, Signature=${gotNameSig}, Data=${gotNameData.toString()}, Uri=${gotNameUri}`);
+    console.log(`Topic=${gotNameTopic}, Scheme=${gotNameScheme}, Issuer=${gotNameIssuer}, Signature=${gotNameSig}, Data=${gotNameData.toString()}, Uri=${gotNameUri}`);
 
     //Validate the claim to ensure that it has been issued by the expected issuer
     console.log('\nValidating claim');
-    //validate claim
-    const validateClaimTx = await client.is_claim_valid({
-        identity: identityAddress.toBuffer(),
-        issuer: issuerKeypair.rawPublicKey(),
+    //validate claim with the claim issuer contract
+    const validateClaimTx = await issuerClient.is_claim_valid({
+        identity: identityContractId,
         topic: claimTopic,
         sig: sig,
         data: Buffer.from(data),
+        valid_from: 0,
+        valid_until: 0,
       });
       console.log(`Validation result=${JSON.stringify(validateClaimTx.result)}`);
   })();
\ No newline at end of file